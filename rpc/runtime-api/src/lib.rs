@@ -4,10 +4,17 @@ use codec::Codec;
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::Vec;
 
-use pallet_supersig::{rpc::ProposalState, CallId, Role, SupersigId};
+use pallet_supersig::{
+	rpc::{
+		CallClosure, CallDescription, DepositQuote, DryRunResult, ProposalState, ProposalStateV2,
+		SupersigInfo, SupersigTree,
+	},
+	Allowance, CallId, Role, Salt, SupersigId, VaultIndex,
+};
 use sp_runtime::DispatchError;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(9)]
 	pub trait SuperSigApi<AccountId>
 	where
 		AccountId: Codec,
@@ -16,5 +23,68 @@ sp_api::decl_runtime_apis! {
 		fn list_members(supersig_account: AccountId) -> Result<Vec<(AccountId, Role)>, DispatchError>;
 		fn list_proposals(supersig_account: AccountId) -> Result<(Vec<ProposalState<AccountId>>, u32), DispatchError>;
 		fn get_proposal_state(supersig_account: AccountId, call_id: CallId) -> Result<(ProposalState<AccountId>, u32), DispatchError>;
+		#[api_version(2)]
+		fn get_supersig_tree(supersig_account: AccountId) -> Result<SupersigTree<AccountId>, DispatchError>;
+		#[api_version(3)]
+		fn get_salted_supersig_account(creator: AccountId, salt: Salt) -> AccountId;
+		#[api_version(4)]
+		fn list_members_paged(supersig_account: AccountId, start: Option<AccountId>, limit: u32) -> Result<(Vec<(AccountId, Role)>, Option<AccountId>), DispatchError>;
+		#[api_version(4)]
+		fn list_proposals_paged(supersig_account: AccountId, start: Option<CallId>, limit: u32) -> Result<(Vec<ProposalState<AccountId>>, u32, Option<CallId>), DispatchError>;
+		#[api_version(5)]
+		fn get_call_description(supersig_account: AccountId, call_id: CallId) -> Result<CallDescription, DispatchError>;
+		#[api_version(6)]
+		fn dry_run_call(supersig_account: AccountId, call_id: CallId) -> Result<DryRunResult, DispatchError>;
+		#[api_version(7)]
+		fn list_supersigs(start: Option<SupersigId>, limit: u32) -> (Vec<(SupersigId, AccountId)>, Option<SupersigId>);
+		#[api_version(7)]
+		fn get_supersig_account_by_id(supersig_id: SupersigId) -> Option<AccountId>;
+		#[api_version(7)]
+		fn get_supersig_id(supersig_account: AccountId) -> Option<SupersigId>;
+		#[api_version(8)]
+		fn get_user_supersigs_paged(user_account: AccountId, start: Option<SupersigId>, limit: u32) -> (Vec<SupersigId>, Option<SupersigId>);
+		#[api_version(9)]
+		fn get_closed_calls(supersig_account: AccountId) -> Vec<(CallId, CallClosure)>;
+	}
+
+	/// The methods of `SuperSigApi` that involve balances of the native currency or block
+	/// numbers. It is a separate API, so that runtimes implementing `SuperSigApi` don't need to
+	/// provide those types.
+	#[api_version(2)]
+	pub trait SuperSigBalancesApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		fn get_allowance(supersig_account: AccountId, member: AccountId) -> Result<Option<Allowance<Balance, BlockNumber>>, DispatchError>;
+		#[api_version(2)]
+		fn get_vault_balances(supersig_account: AccountId) -> Result<Vec<(VaultIndex, AccountId, Balance)>, DispatchError>;
+	}
+
+	/// The methods of `SuperSigApi` that involve deposits. `DepositBalance` is the balance type
+	/// of the pallet's `DepositAssets`, and `Balance` the one of its native currency.
+	#[api_version(4)]
+	pub trait SuperSigDepositsApi<AccountId, Balance, DepositBalance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		DepositBalance: Codec,
+	{
+		fn list_proposals_v2(supersig_account: AccountId) -> Result<Vec<ProposalStateV2<AccountId, DepositBalance>>, DispatchError>;
+		fn get_proposal_state_v2(supersig_account: AccountId, call_id: CallId) -> Result<ProposalStateV2<AccountId, DepositBalance>, DispatchError>;
+		#[api_version(2)]
+		fn quote_create_supersig(members: Vec<AccountId>) -> Result<DepositQuote<DepositBalance>, DispatchError>;
+		#[api_version(2)]
+		fn quote_add_members(supersig_account: AccountId, members: Vec<AccountId>) -> Result<DepositBalance, DispatchError>;
+		#[api_version(2)]
+		fn quote_propose_call(encoded_call: Vec<u8>) -> Result<DepositBalance, DispatchError>;
+		/// The open proposals the member can still vote for: in its supersigs, in the collection
+		/// backed supersigs it holds items of, and in the direct parents of its `Role` supersigs.
+		/// Every collection backed supersig is read.
+		#[api_version(3)]
+		fn get_pending_for_member(member: AccountId) -> Vec<(AccountId, ProposalStateV2<AccountId, DepositBalance>)>;
+		#[api_version(4)]
+		fn get_supersig_info(supersig_account: AccountId) -> Result<SupersigInfo<AccountId, Balance, DepositBalance>, DispatchError>;
 	}
 }
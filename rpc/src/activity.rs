@@ -0,0 +1,211 @@
+//! Supersig activity, found by diffing the members and proposals of a supersig between two blocks.
+
+use pallet_supersig::{
+	rpc::{CallClosure, ProposalState},
+	CallId, DispatchError, Role,
+};
+use serde::{Deserialize, Serialize};
+
+/// Something that happened to a supersig between two blocks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum SupersigActivity<AccountId> {
+	/// a call was proposed
+	#[serde(rename_all = "camelCase")]
+	CallProposed {
+		call_id: CallId,
+		provider: AccountId,
+	},
+	/// a member voted for a call
+	#[serde(rename_all = "camelCase")]
+	CallVoted { call_id: CallId, voter: AccountId },
+	/// a call was executed, with this result
+	#[serde(rename_all = "camelCase")]
+	CallExecuted { call_id: CallId, result: Result<(), DispatchError> },
+	/// a call was removed without being executed
+	#[serde(rename_all = "camelCase")]
+	CallRemoved { call_id: CallId },
+	/// a call left the queue in a block that wasn't seen, e.g. on a reorg, or whose runtime
+	/// doesn't tell how calls are closed
+	#[serde(rename_all = "camelCase")]
+	CallClosed { call_id: CallId },
+	/// a member joined the supersig
+	MemberAdded { member: AccountId, role: Role },
+	/// a member left the supersig
+	MemberRemoved { member: AccountId },
+	/// the role of a member changed
+	RoleChanged { member: AccountId, role: Role },
+}
+
+/// The activity of a supersig in a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityNotification<BlockHash, AccountId> {
+	pub block: BlockHash,
+	pub activity: Vec<SupersigActivity<AccountId>>,
+}
+
+/// The state of a supersig the activity is computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ActivitySnapshot<AccountId> {
+	pub members: Vec<(AccountId, Role)>,
+	pub proposals: Vec<ProposalState<AccountId>>,
+	/// the calls that left the queue in the block, and how
+	pub closed: Vec<(CallId, CallClosure)>,
+}
+
+/// The activity that turned `prev` into `next`.
+pub fn diff_activity<AccountId: Clone + PartialEq>(
+	prev: &ActivitySnapshot<AccountId>,
+	next: &ActivitySnapshot<AccountId>,
+) -> Vec<SupersigActivity<AccountId>> {
+	let mut activity = Vec::new();
+
+	for (member, role) in next.members.iter() {
+		match prev.members.iter().find(|(prev_member, _)| prev_member == member) {
+			None => activity.push(SupersigActivity::MemberAdded {
+				member: member.clone(),
+				role: role.clone(),
+			}),
+			Some((_, prev_role)) if prev_role != role =>
+				activity.push(SupersigActivity::RoleChanged {
+					member: member.clone(),
+					role: role.clone(),
+				}),
+			_ => (),
+		}
+	}
+	for (member, _) in prev.members.iter() {
+		if !next.members.iter().any(|(next_member, _)| next_member == member) {
+			activity.push(SupersigActivity::MemberRemoved {
+				member: member.clone(),
+			});
+		}
+	}
+
+	for proposal in next.proposals.iter() {
+		let prev_proposal = prev.proposals.iter().find(|prev| prev.id() == proposal.id());
+		if prev_proposal.is_none() {
+			activity.push(SupersigActivity::CallProposed {
+				call_id: *proposal.id(),
+				provider: proposal.provider().clone(),
+			});
+		}
+		for voter in proposal.voters() {
+			if !prev_proposal.map_or(false, |prev| prev.voters().contains(voter)) {
+				activity.push(SupersigActivity::CallVoted {
+					call_id: *proposal.id(),
+					voter: voter.clone(),
+				});
+			}
+		}
+	}
+	for proposal in prev.proposals.iter() {
+		if !next.proposals.iter().any(|next| next.id() == proposal.id()) {
+			let call_id = *proposal.id();
+			let closure = next.closed.iter().find(|(closed_id, _)| *closed_id == call_id);
+			activity.push(match closure {
+				Some((_, CallClosure::Executed(result))) => SupersigActivity::CallExecuted {
+					call_id,
+					result: result.clone(),
+				},
+				Some((_, CallClosure::Removed)) => SupersigActivity::CallRemoved { call_id },
+				None => SupersigActivity::CallClosed { call_id },
+			});
+		}
+	}
+
+	activity
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn proposal(id: CallId, voters: Vec<u64>) -> ProposalState<u64> {
+		ProposalState::new(id, vec![0], 1, voters)
+	}
+
+	#[test]
+	fn no_activity() {
+		let snapshot = ActivitySnapshot {
+			members: vec![(1, Role::Master)],
+			proposals: vec![proposal(0, vec![1])],
+			closed: vec![],
+		};
+		assert_eq!(diff_activity(&snapshot, &snapshot), vec![]);
+	}
+
+	#[test]
+	fn membership_changes() {
+		let prev = ActivitySnapshot {
+			members: vec![(1, Role::Master), (2, Role::Standard)],
+			proposals: vec![],
+			closed: vec![],
+		};
+		let next = ActivitySnapshot {
+			members: vec![(1, Role::Standard), (3, Role::Standard)],
+			proposals: vec![],
+			closed: vec![],
+		};
+		assert_eq!(
+			diff_activity(&prev, &next),
+			vec![
+				SupersigActivity::RoleChanged {
+					member: 1,
+					role: Role::Standard
+				},
+				SupersigActivity::MemberAdded {
+					member: 3,
+					role: Role::Standard
+				},
+				SupersigActivity::MemberRemoved { member: 2 },
+			]
+		);
+	}
+
+	#[test]
+	fn proposals_and_votes() {
+		let prev = ActivitySnapshot {
+			members: vec![],
+			proposals: vec![
+				proposal(0, vec![]),
+				proposal(1, vec![2]),
+				proposal(3, vec![]),
+				proposal(4, vec![]),
+			],
+			closed: vec![],
+		};
+		let next = ActivitySnapshot {
+			members: vec![],
+			proposals: vec![proposal(1, vec![2, 3]), proposal(2, vec![1])],
+			// how call 4 left isn't known
+			closed: vec![
+				(0, CallClosure::Executed(Err(DispatchError::BadOrigin))),
+				(3, CallClosure::Removed),
+			],
+		};
+		assert_eq!(
+			diff_activity(&prev, &next),
+			vec![
+				SupersigActivity::CallVoted {
+					call_id: 1,
+					voter: 3
+				},
+				SupersigActivity::CallProposed {
+					call_id: 2,
+					provider: 1
+				},
+				SupersigActivity::CallVoted {
+					call_id: 2,
+					voter: 1
+				},
+				SupersigActivity::CallExecuted {
+					call_id: 0,
+					result: Err(DispatchError::BadOrigin)
+				},
+				SupersigActivity::CallRemoved { call_id: 3 },
+				SupersigActivity::CallClosed { call_id: 4 },
+			]
+		);
+	}
+}
@@ -1,16 +1,60 @@
 use codec::Codec;
+use futures::{future, FutureExt, StreamExt};
 use jsonrpsee::{
 	core::{Error as JsonRpseeError, RpcResult},
 	proc_macros::rpc,
-	types::error::{CallError, ErrorObject},
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
 };
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
 use sp_api::{BlockId, BlockT, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes};
 use std::{marker::PhantomData, sync::Arc};
 
-pub use pallet_supersig_rpc_runtime_api::SuperSigApi as SuperSigRuntimeApi;
+mod activity;
 
-use pallet_supersig::{rpc::ProposalState, CallId, Role, SupersigId};
+pub use activity::{diff_activity, ActivityNotification, ActivitySnapshot, SupersigActivity};
+
+pub use pallet_supersig_rpc_runtime_api::{
+	SuperSigApi as SuperSigRuntimeApi, SuperSigBalancesApi as SuperSigBalancesRuntimeApi,
+	SuperSigDepositsApi as SuperSigDepositsRuntimeApi,
+};
+
+use pallet_supersig::{
+	rpc::{
+		CallClosure, CallDescription, DepositQuote, DispatchClass, DryRunResult, ProposalState,
+		ProposalStateV2, SupersigInfo, SupersigTree, Weight,
+	},
+	Allowance, CallId, DispatchError, Role, Salt, SupersigId, VaultIndex,
+};
+
+/// A proposed call in a human readable form, next to its SCALE encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadableCall {
+	pub encoded_call: Bytes,
+	pub pallet: String,
+	pub function: String,
+	pub weight: Weight,
+	pub class: DispatchClass,
+}
+
+impl From<CallDescription> for ReadableCall {
+	fn from(description: CallDescription) -> Self {
+		Self {
+			encoded_call: description.encoded_call().clone().into(),
+			pallet: String::from_utf8_lossy(description.pallet_name()).into_owned(),
+			function: String::from_utf8_lossy(description.function_name()).into_owned(),
+			weight: *description.weight(),
+			class: *description.class(),
+		}
+	}
+}
 
 #[rpc(client, server)]
 pub trait SuperSigApi<BlockHash, AccountId> {
@@ -20,6 +64,14 @@ pub trait SuperSigApi<BlockHash, AccountId> {
 		user_account: AccountId,
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<SupersigId>>;
+	#[method(name = "superSig_getUserSupersigsPaged")]
+	fn get_user_supersigs_paged(
+		&self,
+		user_account: AccountId,
+		start: Option<SupersigId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<SupersigId>, Option<SupersigId>)>;
 	#[method(name = "superSig_listMembers")]
 	fn list_members(
 		&self,
@@ -39,31 +91,238 @@ pub trait SuperSigApi<BlockHash, AccountId> {
 		call_id: CallId,
 		at: Option<BlockHash>,
 	) -> RpcResult<(ProposalState<AccountId>, u32)>;
+	#[method(name = "superSig_getSupersigTree")]
+	fn get_supersig_tree(
+		&self,
+		supersig_account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<SupersigTree<AccountId>>;
+	#[method(name = "superSig_getSaltedSupersigAccount")]
+	fn get_salted_supersig_account(
+		&self,
+		creator: AccountId,
+		salt: Salt,
+		at: Option<BlockHash>,
+	) -> RpcResult<AccountId>;
+	#[method(name = "superSig_listMembersPaged")]
+	fn list_members_paged(
+		&self,
+		supersig_account: AccountId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(AccountId, Role)>, Option<AccountId>)>;
+	#[method(name = "superSig_listProposalsPaged")]
+	fn list_proposals_paged(
+		&self,
+		supersig_account: AccountId,
+		start: Option<CallId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<ProposalState<AccountId>>, u32, Option<CallId>)>;
+	#[method(name = "superSig_getCallDescription")]
+	fn get_call_description(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ReadableCall>;
+	#[method(name = "superSig_dryRunProposal")]
+	fn dry_run_proposal(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<BlockHash>,
+	) -> RpcResult<DryRunOutcome>;
+	#[method(name = "superSig_listSupersigs")]
+	fn list_supersigs(
+		&self,
+		start: Option<SupersigId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<(Vec<(SupersigId, AccountId)>, Option<SupersigId>)>;
+	#[method(name = "superSig_getSupersigAccountById")]
+	fn get_supersig_account_by_id(
+		&self,
+		supersig_id: SupersigId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+	#[method(name = "superSig_getSupersigId")]
+	fn get_supersig_id(
+		&self,
+		supersig_account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<SupersigId>>;
+	/// Follow the activity of a supersig: its proposals, votes, closed calls and membership
+	/// changes. The blocks followed are the new best blocks, or the finalized ones if
+	/// `finalized` is true.
+	#[subscription(
+		name = "superSig_subscribeActivity" => "superSig_activity",
+		unsubscribe = "superSig_unsubscribeActivity",
+		item = ActivityNotification<BlockHash, AccountId>
+	)]
+	fn subscribe_activity(&self, supersig_account: AccountId, finalized: Option<bool>);
+}
+
+/// The methods that involve balances or block numbers, with the runtime API of the same name.
+#[rpc(client, server)]
+pub trait SuperSigBalancesApi<BlockHash, AccountId, Balance, BlockNumber> {
+	#[method(name = "superSig_getAllowance")]
+	fn get_allowance(
+		&self,
+		supersig_account: AccountId,
+		member: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Allowance<Balance, BlockNumber>>>;
+	#[method(name = "superSig_getVaultBalances")]
+	fn get_vault_balances(
+		&self,
+		supersig_account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(VaultIndex, AccountId, Balance)>>;
+}
+
+/// The methods that involve deposits, with the runtime API of the same name.
+#[rpc(client, server)]
+pub trait SuperSigDepositsApi<BlockHash, AccountId, Balance, DepositBalance> {
+	#[method(name = "superSig_listProposalsV2")]
+	fn list_proposals_v2(
+		&self,
+		supersig_account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProposalStateV2<AccountId, DepositBalance>>>;
+	#[method(name = "superSig_getProposalStateV2")]
+	fn get_proposal_state_v2(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProposalStateV2<AccountId, DepositBalance>>;
+	#[method(name = "superSig_quoteCreateSupersig")]
+	fn quote_create_supersig(
+		&self,
+		members: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<DepositQuote<DepositBalance>>;
+	#[method(name = "superSig_quoteAddMembers")]
+	fn quote_add_members(
+		&self,
+		supersig_account: AccountId,
+		members: Vec<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<DepositBalance>;
+	#[method(name = "superSig_quoteProposeCall")]
+	fn quote_propose_call(
+		&self,
+		encoded_call: Bytes,
+		at: Option<BlockHash>,
+	) -> RpcResult<DepositBalance>;
+	/// The open proposals the member can still vote for: in its supersigs, in the collection
+	/// backed supersigs it holds items of, and in the direct parents of its `Role` supersigs,
+	/// with `approve_call_as_child`. Every collection backed supersig is read.
+	#[method(name = "superSig_getPendingForMember")]
+	fn get_pending_for_member(
+		&self,
+		member: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, ProposalStateV2<AccountId, DepositBalance>)>>;
+	#[method(name = "superSig_getSupersigInfo")]
+	fn get_supersig_info(
+		&self,
+		supersig_account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<SupersigInfo<AccountId, Balance, DepositBalance>>;
+}
+
+/// The outcome of a dry run of a proposed call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunOutcome {
+	pub result: Result<(), DispatchError>,
+	pub actual_weight: Weight,
+	/// the SCALE encoded runtime events emitted by the call
+	pub events: Vec<Bytes>,
+}
+
+impl From<DryRunResult> for DryRunOutcome {
+	fn from(dry_run: DryRunResult) -> Self {
+		Self {
+			result: dry_run.result().map(|_| ()).map_err(|err| err.error),
+			actual_weight: *dry_run.actual_weight(),
+			events: dry_run.events().iter().cloned().map(Bytes).collect(),
+		}
+	}
 }
 
 /// SuperSig RPC methods.
 pub struct SuperSig<Client, Block> {
 	client: Arc<Client>,
+	executor: Option<Arc<dyn SpawnNamed>>,
 	_marker: PhantomData<Block>,
 }
 
 impl<Client, Block> SuperSig<Client, Block> {
-	/// Create new `Supersig` with the given reference to the client.
+	/// Create new `Supersig` with the given reference to the client. Subscriptions are rejected,
+	/// use `with_executor` to serve them.
 	pub fn new(client: Arc<Client>) -> Self {
 		Self {
 			client,
+			executor: None,
+			_marker: Default::default(),
+		}
+	}
+
+	/// Create new `Supersig` with the given reference to the client, and the executor running
+	/// the subscriptions.
+	pub fn with_executor(client: Arc<Client>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self {
+			client,
+			executor: Some(executor),
 			_marker: Default::default(),
 		}
 	}
 }
 
+/// The members and proposals of a supersig at the given block, and the calls closed in it.
+fn activity_snapshot<Client, Block, AccountId>(
+	client: &Client,
+	at: <Block as BlockT>::Hash,
+	supersig_account: AccountId,
+) -> Result<ActivitySnapshot<AccountId>, JsonRpseeError>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: SuperSigRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Clone,
+{
+	let api = client.runtime_api();
+	let at = BlockId::hash(at);
+	let members = api
+		.list_members(&at, supersig_account.clone())
+		.map_err(runtime_error_into_rpc_err)?
+		.map_err(supersig_rpc_error)?;
+	let (proposals, _) = api
+		.list_proposals(&at, supersig_account.clone())
+		.map_err(runtime_error_into_rpc_err)?
+		.map_err(supersig_rpc_error)?;
+	// Runtimes older than the version 9 of the API don't tell how the calls were closed
+	let closed = api.get_closed_calls(&at, supersig_account).unwrap_or_default();
+
+	Ok(ActivitySnapshot { members, proposals, closed })
+}
+
 impl<Client, Block, AccountId> SuperSigApiServer<<Block as BlockT>::Hash, AccountId>
 	for SuperSig<Client, Block>
 where
 	Block: BlockT,
-	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client: Send
+		+ Sync
+		+ 'static
+		+ ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>,
 	Client::Api: SuperSigRuntimeApi<Block, AccountId>,
-	AccountId: Codec,
+	AccountId: Codec + Clone + PartialEq + Serialize + Send + Sync + 'static,
 {
 	fn get_user_supersigs(
 		&self,
@@ -78,6 +337,22 @@ where
 		Ok(supersigs)
 	}
 
+	fn get_user_supersigs_paged(
+		&self,
+		user_account: AccountId,
+		start: Option<SupersigId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Vec<SupersigId>, Option<SupersigId>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let page = api
+			.get_user_supersigs_paged(&at, user_account, start, limit)
+			.map_err(runtime_error_into_rpc_err)?;
+
+		Ok(page)
+	}
+
 	fn list_members(
 		&self,
 		supersig_account: AccountId,
@@ -123,10 +398,368 @@ where
 
 		Ok(state)
 	}
+
+	fn get_supersig_tree(
+		&self,
+		supersig_account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SupersigTree<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let tree = api
+			.get_supersig_tree(&at, supersig_account)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(tree)
+	}
+
+	fn get_salted_supersig_account(
+		&self,
+		creator: AccountId,
+		salt: Salt,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AccountId> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let account = api
+			.get_salted_supersig_account(&at, creator, salt)
+			.map_err(runtime_error_into_rpc_err)?;
+
+		Ok(account)
+	}
+
+	fn list_members_paged(
+		&self,
+		supersig_account: AccountId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Vec<(AccountId, Role)>, Option<AccountId>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let page = api
+			.list_members_paged(&at, supersig_account, start, limit)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(page)
+	}
+
+	fn list_proposals_paged(
+		&self,
+		supersig_account: AccountId,
+		start: Option<CallId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Vec<ProposalState<AccountId>>, u32, Option<CallId>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let page = api
+			.list_proposals_paged(&at, supersig_account, start, limit)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(page)
+	}
+
+	fn get_call_description(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ReadableCall> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let description = api
+			.get_call_description(&at, supersig_account, call_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(description.into())
+	}
+
+	fn dry_run_proposal(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<DryRunOutcome> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let dry_run = api
+			.dry_run_call(&at, supersig_account, call_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(dry_run.into())
+	}
+
+	fn list_supersigs(
+		&self,
+		start: Option<SupersigId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(Vec<(SupersigId, AccountId)>, Option<SupersigId>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let page = api.list_supersigs(&at, start, limit).map_err(runtime_error_into_rpc_err)?;
+
+		Ok(page)
+	}
+
+	fn get_supersig_account_by_id(
+		&self,
+		supersig_id: SupersigId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let account = api
+			.get_supersig_account_by_id(&at, supersig_id)
+			.map_err(runtime_error_into_rpc_err)?;
+
+		Ok(account)
+	}
+
+	fn get_supersig_id(
+		&self,
+		supersig_account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<SupersigId>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let supersig_id =
+			api.get_supersig_id(&at, supersig_account).map_err(runtime_error_into_rpc_err)?;
+
+		Ok(supersig_id)
+	}
+
+	fn subscribe_activity(
+		&self,
+		mut sink: SubscriptionSink,
+		supersig_account: AccountId,
+		finalized: Option<bool>,
+	) -> SubscriptionResult {
+		let executor = match self.executor.clone() {
+			Some(executor) => executor,
+			None => {
+				let reason = "no executor, see `SuperSig::with_executor`";
+				let _ = sink.reject(subscription_error(reason));
+				return Ok(())
+			},
+		};
+		let client = self.client.clone();
+		let finalized = finalized.unwrap_or(false);
+		let (start, blocks) = if finalized {
+			let blocks = client.finality_notification_stream().map(|block| block.hash).boxed();
+			(client.info().finalized_hash, blocks)
+		} else {
+			let blocks = client
+				.import_notification_stream()
+				.filter(|block| future::ready(block.is_new_best))
+				.map(|block| block.hash)
+				.boxed();
+			(client.info().best_hash, blocks)
+		};
+
+		let mut snapshot =
+			match activity_snapshot::<_, Block, _>(&*client, start, supersig_account.clone()) {
+				Ok(snapshot) => snapshot,
+				Err(err) => {
+					let _ = sink.reject(err);
+					return Ok(())
+				},
+			};
+
+		// Blocks the supersig can't be read at, e.g. once it is deleted, are skipped
+		let notifications = blocks.filter_map(move |hash| {
+			let notification =
+				activity_snapshot::<_, Block, _>(&*client, hash, supersig_account.clone())
+					.ok()
+					.and_then(|next| {
+						let activity = diff_activity(&snapshot, &next);
+						snapshot = next;
+						(!activity.is_empty()).then_some(ActivityNotification {
+							block: hash,
+							activity,
+						})
+					});
+			future::ready(notification)
+		});
+
+		let fut = async move {
+			sink.pipe_from_stream(notifications).await;
+		};
+		executor.spawn("supersig-rpc-subscription", Some("rpc"), fut.boxed());
+
+		Ok(())
+	}
+}
+
+impl<Client, Block, AccountId, Balance, BlockNumber>
+	SuperSigBalancesApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
+	for SuperSig<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client::Api: SuperSigBalancesRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	AccountId: Codec + Send + 'static,
+	Balance: Codec + Send + 'static,
+	BlockNumber: Codec + Send + 'static,
+{
+	fn get_allowance(
+		&self,
+		supersig_account: AccountId,
+		member: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<Allowance<Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let allowance = api
+			.get_allowance(&at, supersig_account, member)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(allowance)
+	}
+
+	fn get_vault_balances(
+		&self,
+		supersig_account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(VaultIndex, AccountId, Balance)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let vaults = api
+			.get_vault_balances(&at, supersig_account)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(vaults)
+	}
+}
+
+impl<Client, Block, AccountId, Balance, DepositBalance>
+	SuperSigDepositsApiServer<<Block as BlockT>::Hash, AccountId, Balance, DepositBalance>
+	for SuperSig<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client::Api: SuperSigDepositsRuntimeApi<Block, AccountId, Balance, DepositBalance>,
+	AccountId: Codec + Send + 'static,
+	Balance: Codec + Send + 'static,
+	DepositBalance: Codec + Send + 'static,
+{
+	fn list_proposals_v2(
+		&self,
+		supersig_account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProposalStateV2<AccountId, DepositBalance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let proposals = api
+			.list_proposals_v2(&at, supersig_account)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(proposals)
+	}
+
+	fn get_proposal_state_v2(
+		&self,
+		supersig_account: AccountId,
+		call_id: CallId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ProposalStateV2<AccountId, DepositBalance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let state = api
+			.get_proposal_state_v2(&at, supersig_account, call_id)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(state)
+	}
+
+	fn quote_create_supersig(
+		&self,
+		members: Vec<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<DepositQuote<DepositBalance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let quote = api
+			.quote_create_supersig(&at, members)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(quote)
+	}
+
+	fn quote_add_members(
+		&self,
+		supersig_account: AccountId,
+		members: Vec<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<DepositBalance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let deposit = api
+			.quote_add_members(&at, supersig_account, members)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(deposit)
+	}
+
+	fn quote_propose_call(
+		&self,
+		encoded_call: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<DepositBalance> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let deposit = api
+			.quote_propose_call(&at, encoded_call.to_vec())
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(deposit)
+	}
+
+	fn get_pending_for_member(
+		&self,
+		member: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, ProposalStateV2<AccountId, DepositBalance>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let pending =
+			api.get_pending_for_member(&at, member).map_err(runtime_error_into_rpc_err)?;
+
+		Ok(pending)
+	}
+
+	fn get_supersig_info(
+		&self,
+		supersig_account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SupersigInfo<AccountId, Balance, DepositBalance>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let info = api
+			.get_supersig_info(&at, supersig_account)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(supersig_rpc_error)?;
+
+		Ok(info)
+	}
 }
 
 const RUNTIME_ERROR: i32 = 1;
 const SUPERSIG_ERROR: i32 = 2;
+const SUBSCRIPTION_ERROR: i32 = 3;
 
 /// Converts a runtime trap into an RPC error.
 fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
@@ -146,3 +779,168 @@ fn supersig_rpc_error(err: impl std::fmt::Debug) -> JsonRpseeError {
 	))
 	.into()
 }
+
+fn subscription_error(reason: &str) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		SUBSCRIPTION_ERROR,
+		"Subscription error",
+		Some(reason.to_string()),
+	))
+	.into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_block_builder::BlockBuilderProvider;
+	use sp_api::{ApiError, ApiRef};
+	use sp_blockchain::{BlockStatus, Info};
+	use sp_consensus::BlockOrigin;
+	use sp_core::{testing::TaskExecutor, H256};
+	use substrate_test_runtime_client::{
+		prelude::*,
+		runtime::{Block, Header},
+		TestClient,
+	};
+
+	// The test client, whose supersig runtime API is mocked
+	struct SupersigClient {
+		client: Arc<TestClient>,
+	}
+
+	// Supersig 0 gains a member at each block, the member being the block number
+	#[derive(Clone)]
+	struct MockApi {
+		client: Arc<TestClient>,
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl pallet_supersig_rpc_runtime_api::SuperSigApi<Block, u64> for MockApi {
+			#[advanced]
+			fn list_members(
+				&self,
+				at: &BlockId<Block>,
+				_supersig_account: u64,
+			) -> Result<Result<Vec<(u64, Role)>, DispatchError>, ApiError> {
+				let number = match at {
+					BlockId::Hash(hash) =>
+						self.client.number(*hash).ok().flatten().unwrap_or_default(),
+					BlockId::Number(number) => *number,
+				};
+				Ok(Ok((0..=number).map(|member| (member, Role::Standard)).collect()))
+			}
+
+			fn list_proposals(
+				_supersig_account: u64,
+			) -> Result<(Vec<ProposalState<u64>>, u32), DispatchError> {
+				Ok((vec![], 0))
+			}
+
+			fn get_closed_calls(_supersig_account: u64) -> Vec<(CallId, CallClosure)> {
+				vec![]
+			}
+		}
+	}
+
+	impl ProvideRuntimeApi<Block> for SupersigClient {
+		type Api = MockApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			MockApi {
+				client: self.client.clone(),
+			}
+			.into()
+		}
+	}
+
+	impl HeaderBackend<Block> for SupersigClient {
+		fn header(&self, hash: H256) -> sp_blockchain::Result<Option<Header>> {
+			self.client.header(hash)
+		}
+
+		fn info(&self) -> Info<Block> {
+			self.client.info()
+		}
+
+		fn status(&self, hash: H256) -> sp_blockchain::Result<BlockStatus> {
+			self.client.status(hash)
+		}
+
+		fn number(&self, hash: H256) -> sp_blockchain::Result<Option<u64>> {
+			self.client.number(hash)
+		}
+
+		fn hash(&self, number: u64) -> sp_blockchain::Result<Option<H256>> {
+			self.client.hash(number)
+		}
+	}
+
+	impl BlockchainEvents<Block> for SupersigClient {
+		fn every_import_notification_stream(&self) -> sc_client_api::ImportNotifications<Block> {
+			self.client.every_import_notification_stream()
+		}
+
+		fn import_notification_stream(&self) -> sc_client_api::ImportNotifications<Block> {
+			self.client.import_notification_stream()
+		}
+
+		fn finality_notification_stream(&self) -> sc_client_api::FinalityNotifications<Block> {
+			self.client.finality_notification_stream()
+		}
+
+		fn storage_changes_notification_stream(
+			&self,
+			filter_keys: Option<&[sc_client_api::StorageKey]>,
+			child_filter_keys: Option<
+				&[(sc_client_api::StorageKey, Option<Vec<sc_client_api::StorageKey>>)],
+			>,
+		) -> sp_blockchain::Result<sc_client_api::StorageEventStream<H256>> {
+			self.client.storage_changes_notification_stream(filter_keys, child_filter_keys)
+		}
+	}
+
+	async fn import_block(client: &mut Arc<TestClient>) -> H256 {
+		let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+		let hash = block.header.hash();
+		client.import(BlockOrigin::Own, block).await.unwrap();
+		hash
+	}
+
+	#[tokio::test]
+	async fn subscribe_activity() {
+		let mut client = Arc::new(TestClientBuilder::new().build());
+		let api = SuperSig::with_executor(
+			Arc::new(SupersigClient {
+				client: client.clone(),
+			}),
+			Arc::new(TaskExecutor::new()),
+		)
+		.into_rpc();
+
+		let mut sub = api.subscribe("superSig_subscribeActivity", [0u64]).await.unwrap();
+
+		for member in 1..=2 {
+			let hash = import_block(&mut client).await;
+			let (notification, _) =
+				sub.next::<ActivityNotification<H256, u64>>().await.unwrap().unwrap();
+			assert_eq!(
+				notification,
+				ActivityNotification {
+					block: hash,
+					activity: vec![SupersigActivity::MemberAdded {
+						member,
+						role: Role::Standard
+					}],
+				}
+			);
+		}
+	}
+
+	#[tokio::test]
+	async fn subscribe_activity_needs_an_executor() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let api = SuperSig::<_, Block>::new(Arc::new(SupersigClient { client })).into_rpc();
+
+		assert!(api.subscribe("superSig_subscribeActivity", [0u64]).await.is_err());
+	}
+}
@@ -4,9 +4,16 @@
 use super::*;
 
 use crate::Pallet;
-use frame_benchmarking::{account as benchmark_account, benchmarks};
-use frame_support::{assert_ok, storage::bounded_vec::*, traits::Get, PalletId};
+use codec::Decode;
+use frame_benchmarking::{account as benchmark_account, benchmarks, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	storage::bounded_vec::*,
+	traits::{fungible::Mutate, EnsureOrigin, Get},
+	PalletId,
+};
 use frame_system::RawOrigin;
+use sp_runtime::{traits::TrailingZeroInput, SaturatedConversion};
 use sp_std::vec;
 
 pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
@@ -14,13 +21,22 @@ pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	account
 }
 
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+	T::Currency::set_balance(who, amount);
+	<T::DepositAssets as fungibles::Mutate<_>>::set_balance(
+		T::DepositAssetId::get(),
+		who,
+		amount.saturated_into::<u128>().saturated_into(),
+	);
+}
+
 benchmarks! {
 	create_supersig {
 		let z in 0 .. T::MaxAccountsPerTransaction::get() - 1;
 
 		let alice: T::AccountId = get_account::<T>("ALICE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 		let mut members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard)}.try_into().unwrap();
 		let oui = "oui";
@@ -43,7 +59,7 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 
 		let supersig_id = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
@@ -68,8 +84,8 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
-		T::Currency::make_free_balance_be(&bob, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&bob, val.saturating_mul(4_000_000_000u32.into()));
 
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
 
@@ -97,7 +113,7 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
 
@@ -122,7 +138,7 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 		let initial_members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob, Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
 		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), initial_members.clone()));
@@ -134,7 +150,7 @@ benchmarks! {
 			new_members.try_push((acc, Role::Standard)).unwrap();
 		}
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
-		T::Currency::make_free_balance_be(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
 
 	}: _(RawOrigin::Signed(supersig_id.clone()), new_members.clone())
 	verify {
@@ -147,7 +163,7 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 		let initial_members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob, Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
 		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), initial_members.clone()));
@@ -159,7 +175,7 @@ benchmarks! {
 			new_members.try_push((acc, Role::Standard)).unwrap();
 		}
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
-		T::Currency::make_free_balance_be(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
 		assert_ok!(Pallet::<T>::add_members(RawOrigin::Signed(supersig_id.clone()).into(), new_members.clone()));
 
 		let members_to_remove: BoundedVec<T::AccountId, _> = new_members.into_iter().map(|(a, r)| a).collect::<Vec<_>>().try_into().unwrap();
@@ -173,9 +189,9 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 4_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
-		T::Currency::make_free_balance_be(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
 
 		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob.clone(), Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
 
@@ -199,7 +215,7 @@ benchmarks! {
 		let bob: T::AccountId = get_account::<T>("BOB");
 		let charlie: T::AccountId = get_account::<T>("CHARLIE");
 		let val: BalanceOf<T> = 1_000_000_000u32.into();
-		T::Currency::make_free_balance_be(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
 
 		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
 		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob.clone(), Role::Standard), (charlie.clone(), Role::Standard)}.try_into().unwrap();
@@ -215,4 +231,390 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::members(0, bob), Role::Standard);
 		assert_eq!(Pallet::<T>::members(0, charlie), Role::Standard);
 	}
+
+	rotate_member {
+		let z in 0 .. T::MaxCallsPerAccount::get();
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let charlie: T::AccountId = get_account::<T>("CHARLIE");
+		let paul: T::AccountId = get_account::<T>("PAUL");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master), (bob, Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
+
+		assert_ok!(
+			Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(),
+			members)
+		);
+		for _ in 0 .. z {
+			let call = frame_system::Call::remark {
+				remark: vec![0; 0]
+			}.into();
+			assert_ok!(Pallet::<T>::propose_call(RawOrigin::Signed(alice.clone()).into(), supersig_id.clone(), Box::new(call)));
+		}
+		for i in 0 .. z {
+			assert_ok!(Pallet::<T>::approve_call(RawOrigin::Signed(alice.clone()).into(), supersig_id.clone(), i.into()));
+		}
+	}: _(RawOrigin::Signed(alice.clone()), supersig_id, alice.clone(), paul.clone())
+	verify {
+		assert_eq!(Pallet::<T>::members(0, alice), Role::NotMember);
+		assert_eq!(Pallet::<T>::members(0, paul), Role::Master);
+		assert_eq!(Pallet::<T>::total_members(0), 3);
+	}
+
+	set_invariants {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let charlie: T::AccountId = get_account::<T>("CHARLIE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master), (bob, Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
+
+		assert_ok!(
+			Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(),
+			members)
+		);
+		let invariants = Invariants { min_masters: 1, min_total_weight: 3 };
+	}: _(RawOrigin::Signed(supersig_id), invariants.clone())
+	verify {
+		assert_eq!(Pallet::<T>::invariants(0), invariants);
+	}
+
+	set_voting_mode {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let charlie: T::AccountId = get_account::<T>("CHARLIE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master), (bob, Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
+
+		assert_ok!(
+			Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(),
+			members)
+		);
+	}: _(RawOrigin::Signed(supersig_id), VotingMode::Role)
+	verify {
+		assert_eq!(Pallet::<T>::voting_mode(0), VotingMode::Role);
+	}
+
+	create_supersig_from_collection {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+		let collection = T::CollectionId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+	}: _(RawOrigin::Signed(alice), collection.clone())
+	verify {
+		assert_eq!(Pallet::<T>::nonce_supersig(), 1);
+		assert_eq!(Pallet::<T>::voting_mode(0), VotingMode::Collection(collection));
+	}
+
+	approve_call_as_child {
+		let call = frame_system::Call::remark {
+			remark: vec![0; 0]
+		}.into();
+
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let charlie: T::AccountId = get_account::<T>("CHARLIE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let child_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let parent_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(1);
+
+		let child_members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob, Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), child_members));
+		let parent_members: BoundedVec<_, _> = vec!{(child_id.clone(), Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), parent_members));
+		assert_ok!(Pallet::<T>::propose_call(RawOrigin::Signed(alice.clone()).into(), parent_id.clone(), Box::new(call)));
+	}: _(RawOrigin::Signed(alice.clone()), child_id, parent_id, 0)
+	verify {
+		assert_eq!(Pallet::<T>::child_votes((1, 0, 0)), 1);
+	}
+
+	set_allowance {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob, Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+	}: _(RawOrigin::Signed(supersig_id), alice.clone(), val, 100u32.into())
+	verify {
+		assert!(Pallet::<T>::allowances(0, alice).is_some());
+	}
+
+	spend_allowance {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob.clone(), Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		fund::<T>(&supersig_id, val.saturating_mul(4_000_000_000u32.into()));
+		assert_ok!(Pallet::<T>::set_allowance(RawOrigin::Signed(supersig_id.clone()).into(), alice.clone(), val, 100u32.into()));
+	}: _(RawOrigin::Signed(alice.clone()), supersig_id, bob, val)
+	verify {
+		assert!(Pallet::<T>::allowances(0, alice).unwrap().remaining.is_zero());
+	}
+
+	set_outflow_limit {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), members));
+		let limit = OutflowLimit { max_amount: val, window: 100u32.into() };
+	}: _(RawOrigin::Signed(supersig_id), Some(limit.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::outflow_limits(0), Some(limit));
+	}
+
+	create_supersig_with_salt {
+		let z in 0 .. T::MaxAccountsPerTransaction::get() - 1;
+
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let mut members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard)}.try_into().unwrap();
+		let oui = "oui";
+		for i in 0 .. z {
+			let acc = benchmark_account(oui, i, 0);
+			members.try_push((acc, Role::Standard)).unwrap();
+		}
+		let salt = [1u8; 32];
+	}: _(RawOrigin::Signed(alice.clone()), members.clone(), salt)
+	verify {
+		let supersig_account = Pallet::<T>::salted_supersig_account(&alice, &salt);
+		assert_eq!(Pallet::<T>::salted_supersigs(supersig_account), Some(0));
+	}
+
+	set_guardians {
+		let z in 0 .. T::MaxAccountsPerTransaction::get();
+
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), members));
+		let mut guardians: BoundedVec<T::AccountId, _> = vec!{}.try_into().unwrap();
+		let oui = "oui";
+		for i in 0 .. z {
+			guardians.try_push(benchmark_account(oui, i, 0)).unwrap();
+		}
+	}: _(RawOrigin::Signed(supersig_id), guardians.clone())
+	verify {
+		assert_eq!(Pallet::<T>::guardians(0), guardians);
+	}
+
+	freeze {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+	}: _(RawOrigin::Signed(alice), supersig_id)
+	verify {
+		assert!(Pallet::<T>::frozen(0).is_some());
+	}
+
+	unfreeze {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		assert_ok!(Pallet::<T>::freeze(RawOrigin::Signed(alice).into(), supersig_id.clone()));
+	}: _(RawOrigin::Signed(supersig_id))
+	verify {
+		assert!(Pallet::<T>::frozen(0).is_none());
+	}
+
+	set_inheritance {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		let inheritance = Inheritance {
+			beneficiary: bob.clone(),
+			inactivity_period: 10u32.into(),
+			challenge_period: 10u32.into(),
+		};
+	}: _(RawOrigin::Signed(supersig_id), Some(inheritance.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::inheritances(0), Some(inheritance));
+	}
+
+	claim_inheritance {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		let inheritance = Inheritance {
+			beneficiary: bob.clone(),
+			inactivity_period: 10u32.into(),
+			challenge_period: 10u32.into(),
+		};
+		assert_ok!(Pallet::<T>::set_inheritance(RawOrigin::Signed(supersig_id.clone()).into(), Some(inheritance)));
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+	}: _(RawOrigin::Signed(bob), supersig_id)
+	verify {
+		assert!(Pallet::<T>::inheritance_claims(0).is_some());
+	}
+
+	cancel_inheritance_claim {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		let inheritance = Inheritance {
+			beneficiary: bob.clone(),
+			inactivity_period: 10u32.into(),
+			challenge_period: 10u32.into(),
+		};
+		assert_ok!(Pallet::<T>::set_inheritance(RawOrigin::Signed(supersig_id.clone()).into(), Some(inheritance)));
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+		assert_ok!(Pallet::<T>::claim_inheritance(RawOrigin::Signed(bob).into(), supersig_id.clone()));
+	}: _(RawOrigin::Signed(alice), supersig_id)
+	verify {
+		assert!(Pallet::<T>::inheritance_claims(0).is_none());
+	}
+
+	execute_inheritance {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		let inheritance = Inheritance {
+			beneficiary: bob.clone(),
+			inactivity_period: 10u32.into(),
+			challenge_period: 10u32.into(),
+		};
+		assert_ok!(Pallet::<T>::set_inheritance(RawOrigin::Signed(supersig_id.clone()).into(), Some(inheritance)));
+		frame_system::Pallet::<T>::set_block_number(20u32.into());
+		assert_ok!(Pallet::<T>::claim_inheritance(RawOrigin::Signed(bob.clone()).into(), supersig_id.clone()));
+		frame_system::Pallet::<T>::set_block_number(30u32.into());
+	}: _(RawOrigin::Signed(bob), supersig_id)
+	verify {
+		assert_eq!(Pallet::<T>::total_members(0), 0);
+	}
+
+	propose_call_with_origin {
+		let z in 0 .. 100_000;
+		let call = frame_system::Call::remark {
+			remark: vec![0; z as usize]
+		}.into();
+
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob, Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+	}: _(RawOrigin::Signed(alice), supersig_id, Box::new(call), CallOrigin::Approved, None)
+	verify {
+		assert_eq!(Pallet::<T>::call_origins(0, 0), CallOrigin::Approved);
+	}
+
+	add_vault {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), members));
+	}: _(RawOrigin::Signed(supersig_id))
+	verify {
+		assert_eq!(Pallet::<T>::vault_count(0), 1);
+	}
+
+	// Needs an `XcmRouter` that can reach the parent chain, for a price in the native currency
+	send_xcm {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice).into(), members));
+		fund::<T>(&supersig_id, val);
+		let dest = VersionedMultiLocation::from(MultiLocation::parent());
+		let message = VersionedXcm::from(Xcm(vec![xcm::latest::Instruction::ClearOrigin]));
+	}: _(RawOrigin::Signed(supersig_id), Box::new(dest), Box::new(message))
+
+	set_remote_voter {
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Master)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		let location = VersionedMultiLocation::from(MultiLocation::parent());
+	}: _(RawOrigin::Signed(supersig_id), Box::new(location), Some(alice.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::remote_voters(0, MultiLocation::parent()), Some(alice));
+	}
+
+	approve_call_remote {
+		let call = frame_system::Call::remark {
+			remark: vec![0; 0]
+		}.into();
+
+		let alice: T::AccountId = get_account::<T>("ALICE");
+		let bob: T::AccountId = get_account::<T>("BOB");
+		let charlie: T::AccountId = get_account::<T>("CHARLIE");
+		let val: BalanceOf<T> = 4_000_000_000u32.into();
+		fund::<T>(&alice, val.saturating_mul(4_000_000_000u32.into()));
+
+		let supersig_id: T::AccountId = <<T as Config>::PalletId as Get<PalletId>>::get().into_sub_account_truncating(0);
+		let members: BoundedVec<_, _> = vec!{(alice.clone(), Role::Standard), (bob.clone(), Role::Standard), (charlie, Role::Standard)}.try_into().unwrap();
+		assert_ok!(Pallet::<T>::create_supersig(RawOrigin::Signed(alice.clone()).into(), members));
+		assert_ok!(Pallet::<T>::propose_call(RawOrigin::Signed(alice).into(), supersig_id.clone(), Box::new(call)));
+
+		let origin = T::XcmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let location = T::XcmOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+		assert_ok!(Pallet::<T>::set_remote_voter(RawOrigin::Signed(supersig_id.clone()).into(), Box::new(location.into()), Some(bob)));
+	}: _<<T as frame_system::Config>::RuntimeOrigin>(origin, supersig_id, 0)
+	verify {
+		assert_eq!(Pallet::<T>::votes(0, 0), 1);
+	}
 }
@@ -26,36 +26,126 @@
 //! - Vote for the transaction
 //! - Remove a pending transaction
 //! - Delete a supersig
+//! - Rotate the key of a member
+//! - Creating a supersig whose membership is backed by an NFT collection
+//! - Vote in a parent supersig through a child supersig
+//! - Spend a per-member allowance without a vote
+//! - Cap the funds executed calls can move out of the supersig
+//! - Creating a supersig at an address known in advance
+//! - Freeze a supersig in an emergency
+//! - Hand an inactive supersig over to a fallback beneficiary
+//! - Dispatch approved calls with an origin other pallets can require
+//! - Keep funds apart in vault sub-accounts
+//! - Act on other chains through XCM
+//! - Vote from other chains through XCM
+//!
+//! Member and call deposits are paid in the `DepositAssetId` asset of `DepositAssets`, e.g. a
+//! stablecoin of pallet-assets, and `DepositPerByte` is priced in that asset. Since pallet-assets
+//! doesn't support holds, the deposits are escrowed: they are transferred to the pallet
+//! `deposit_account`, recorded in storage, and transferred back when released. The native
+//! currency, or any other `fungible`, can be used through `FungibleAsAsset`.
+//!
+//! Calls weighing more than `MaxCallWeight` can't be proposed. The approvals that may execute a
+//! call are charged for it, and refunded the weight it didn't use, whether it succeeds or fails.
 //!
 //!
 //! ### Dispatchable Functions
 //!
 //! - `create_supersig` - Create a supersig, with specified members. The creator will have to
 //!   deposit an existencial balance and a deposit that depend on the number of members, in the
-//!   supersig account. This last amount will be reserved on the supersig
+//!   supersig account. This last amount will be escrowed from the supersig
 //!
 //!   /!!\ Reminder /!!\ the creator of the supersig will NOT be added by default, he will
 //!   have to pass his address into the list of added users.
 //!
 //! - `propose_call` - Submit a proposal for the supersig to execute a transaction, which is an amount corresponding to the
-//!   length of the encoded call will be reserved. The call wraps around the extrinsic which the user is proposing to execute.
+//!   length of the encoded call will be escrowed. The call wraps around the extrinsic which the user is proposing to execute.
 //!    (Anything that requires a vote needs to be wrapped in a proposeCall function.)
 //!
 //! - `approve_call` - Vote for the call to be execute. The threshold is enumerated to vote >= SimpleMajority, the
 //!   call is executed. A user can only approve a call once.
 //!
-//! - `remove_call` - Remove a call from the poll. The reserved amount of the proposer will be unreserved.
+//! - `remove_call` - Remove a call from the poll. The amount escrowed from the proposer will be released.
 //!
 //! - `add_members` - Add new members to the supersig organisation. In case some user are already in the
 //!   supersig, they will be ignored.
 //!
 //! - `remove_members` - Remove members from the supersig. 
 //!
-//! - `delete_supersig` - Remove the supersig and all the associated data. Funds will be unreserved
+//! - `delete_supersig` - Remove the supersig and all the associated data. Funds will be released
 //!   and transfered to specified beneficiary.
 //!
 //! - `leave_supersig` - Elect to leave the supersig. You cannot leave if you are the last member, instead you would
 //!    need to `delete_supersig`
+//!
+//! - `rotate_member` - Replace a member account by a new one, keeping its role and the votes it
+//!   already cast on pending calls. A member whose `approve_call_as_child` votes are still
+//!   pending can't be rotated.
+//!
+//! - `set_invariants` - Set the structural constraints (minimum number of Masters, minimum total
+//!   vote weight) that every later membership change must respect.
+//!
+//! - `set_voting_mode` - Choose how the vote weight of the members is computed: from their `Role`,
+//!   from their balance of a given asset, snapshotted when a call is proposed, or from the items
+//!   of an NFT collection they hold. Calls can't be proposed to a token weighted supersig with
+//!   more than `MaxSnapshotSize` members, or whose members hold none of the asset.
+//!
+//! - `create_supersig_from_collection` - Create a supersig whose members are the holders of the
+//!   items of an NFT collection. Transferring an item transfers the seat. The items are counted
+//!   when a call is proposed, and calls can't be proposed while the collection has more than
+//!   `MaxSnapshotSize` items.
+//!
+//! - `approve_call_as_child` - Vote, as a member of a child supersig, for a call of a parent
+//!   supersig the child is a member of. Once the child threshold is reached, the child supersig
+//!   votes for the call in the parent.
+//!
+//! - `set_allowance` - Grant a member an amount of native currency it can spend from the supersig
+//!   account every period, without a vote.
+//!
+//! - `spend_allowance` - Transfer funds from the supersig account, within the caller allowance.
+//!
+//! - `set_outflow_limit` - Cap the native currency that calls executed by the supersig can move
+//!   out of its account during a sliding window of blocks.
+//!
+//! - `create_supersig_with_salt` - Create a supersig whose account is derived from the creator
+//!   and a salt, so it can be known, and funded, before the supersig exists.
+//!
+//! - `set_guardians` - Set the accounts that can freeze the supersig, on top of its Masters.
+//!
+//! - `freeze` - Immediately stop the activity of the supersig. Only `unfreeze` can then be
+//!   proposed and approved.
+//!
+//! - `unfreeze` - Resume the activity of a frozen supersig.
+//!
+//! - `set_inheritance` - Nominate a beneficiary that can claim the supersig once its members
+//!   have been inactive for a period.
+//!
+//! - `claim_inheritance` - Claim an inactive supersig, as its beneficiary.
+//!
+//! - `cancel_inheritance_claim` - Cancel a pending inheritance claim, as a member.
+//!
+//! - `execute_inheritance` - Remove the supersig in favor of its beneficiary, once the challenge
+//!   period of the claim is over.
+//!
+//! - `propose_call_with_origin` - Submit a proposal that, once approved, is dispatched with the
+//!   pallet `Origin::Approved`, or from a vault, instead of the supersig account.
+//!
+//! - `add_vault` - Add a numbered vault sub-account to the supersig, to keep funds apart.
+//!
+//! - `send_xcm` - Send an XCM message to another chain, on behalf of the supersig.
+//!
+//! - `set_remote_voter` - Allow a location on another chain to vote for a member.
+//!
+//! - `approve_call_remote` - Vote for a call from another chain, through XCM `Transact`.
+//!
+//! Supersigs can be members of other supersigs. A membership change that would make a supersig
+//! one of its own members, directly or through its child supersigs, is rejected.
+//!
+//! Calls dispatched with `Origin::Approved` carry the supersig id, the weight that approved
+//! them and the total weight. Other pallets can require them with
+//! `EnsureSupersigApprovalOf<Id, N, D>`, in the style of pallet-collective.
+//! `EnsureSupersigApproval<N, D>` accepts any supersig, its callers must check the supersig id it
+//! returns.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -69,20 +159,43 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 pub use frame_support::{
 	dispatch::{
 		DispatchError, DispatchErrorWithPostInfo, DispatchResult, GetDispatchInfo, PostDispatchInfo,
 	},
-	traits::{tokens::ExistenceRequirement, Currency, ReservableCurrency},
+	traits::{
+		fungible, fungibles,
+		tokens::{nonfungibles_v2, Preservation},
+		GetCallMetadata,
+	},
 	transactional, PalletId,
 };
 pub use sp_core::Hasher;
+use frame_support::traits::{
+	tokens::{DepositConsequence, Fortitude, Provenance, WithdrawConsequence},
+	EnsureOrigin, Get,
+};
+use sp_std::marker::PhantomData;
 
-pub use sp_runtime::traits::{
-	AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Dispatchable, Hash,
-	Saturating,
+pub use sp_runtime::{
+	traits::{
+		AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Convert, Dispatchable,
+		Hash, Saturating, Zero,
+	},
+	Perbill,
+};
+pub use sp_std::{
+	boxed::Box, cmp::max, collections::btree_set::BTreeSet, mem::size_of, prelude::Vec,
+};
+pub use xcm::{
+	latest::{
+		BodyId, BodyPart, ExecuteXcm, InteriorMultiLocation, Junction, Junctions, MultiLocation,
+		NetworkId, SendXcm, Xcm, XcmHash,
+	},
+	VersionedMultiLocation, VersionedXcm,
 };
-pub use sp_std::{boxed::Box, cmp::max, mem::size_of, prelude::Vec};
 
 pub mod rpc;
 pub mod types;
@@ -91,29 +204,51 @@ pub mod weights;
 pub use types::*;
 pub use weights::*;
 
+pub(crate) const LOG_TARGET: &str = "runtime::supersig";
+
 #[frame_support::pallet]
 pub mod pallet {
 	pub use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_support::dispatch::extract_actual_weight;
+	use frame_support::traits::IsSubType;
 	use frame_system::pallet_prelude::*;
+	use fungible::{Inspect as _, Mutate as _};
+	use fungibles::{Inspect as _, Mutate as _};
+	use nonfungibles_v2::{Inspect as _, InspectEnumerable as _};
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// the obiquitous event type
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The overarching origin, the approved calls are dispatched with
+		type RuntimeOrigin: From<Origin> + From<frame_system::RawOrigin<Self::AccountId>>;
 		/// The trait to manage funds
-		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+		type Currency: fungible::Inspect<Self::AccountId> + fungible::Mutate<Self::AccountId>;
+		/// The assets the deposits are paid in, e.g. pallet-assets, or `FungibleAsAsset<Currency>`
+		/// to pay them in the native currency
+		type DepositAssets: fungibles::Inspect<Self::AccountId>
+			+ fungibles::Mutate<Self::AccountId>;
+		/// The asset of `DepositAssets` the deposits are paid in, e.g. a stablecoin. The first
+		/// deposit also pays the minimum balance of the escrow account, which is never refunded
+		#[pallet::constant]
+		type DepositAssetId: Get<DepositAssetIdOf<Self>>;
 		/// The base id used for accountId calculation
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 		/// The call type
 		type Call: Parameter
-			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ Dispatchable<
+				RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			>
 			+ GetDispatchInfo
-			+ From<frame_system::Call<Self>>;
-		/// The amount of balance that must be deposited per bytes stored
+			+ GetCallMetadata
+			+ From<frame_system::Call<Self>>
+			+ IsSubType<Call<Self>>;
+		/// The amount of the deposit asset that must be deposited per bytes stored
 		#[pallet::constant]
-		type DepositPerByte: Get<BalanceOf<Self>>;
+		type DepositPerByte: Get<DepositBalanceOf<Self>>;
 		/// The maximum number of account that can added or removed in a single call
 		#[pallet::constant]
 		type MaxAccountsPerTransaction: Get<u32>;
@@ -122,34 +257,159 @@ pub mod pallet {
 		/// The maximum size of call data allowed (in bytes).
 		#[pallet::constant]
 		type MaxCallDataSize: Get<u32>;
+		/// The maximum weight of a proposed call. The approvals that may execute a call are charged
+		/// for it, and refunded the weight the call didn't use
+		#[pallet::constant]
+		type MaxCallWeight: Get<Weight>;
 		/// The maximum amount of live proposals there can be per supersig.
 		#[pallet::constant]
 		type MaxCallsPerAccount: Get<u32>;
+		/// The maximum depth of nested supersigs explored when looking for membership cycles
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+		/// The maximum number of members of nested supersigs visited when looking for a
+		/// membership cycle
+		#[pallet::constant]
+		type MaxNestingVisits: Get<u32>;
+		/// The maximum number of vault sub-accounts per supersig
+		#[pallet::constant]
+		type MaxVaults: Get<u32>;
+		/// The maximum number of members of a token weighted supersig, or of items of the collection
+		/// of a collection backed supersig, for a call to be proposed. Their vote weights are
+		/// snapshotted when the call is proposed
+		#[pallet::constant]
+		type MaxSnapshotSize: Get<u32>;
+		/// The maximum number of blocks whose outflows are tracked in the outflow window of a
+		/// supersig. Past it, the oldest ones are merged into the next, making the limit stricter
+		#[pallet::constant]
+		type MaxOutflowBuckets: Get<u32>;
+		/// The router the XCM messages of the supersigs are sent through
+		type XcmRouter: SendXcm;
+		/// The executor charging the supersigs the price `XcmRouter` asks for delivering their
+		/// XCM messages, e.g. `XcmExecutor<XcmConfig>`
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+		/// The location, interior to this chain, a supersig is seen as by the chains it sends XCM
+		/// messages to. e.g. `SupersigAsAccountId32` or `SupersigAsPlurality`
+		type SupersigLocation: Convert<(SupersigId, Self::AccountId), InteriorMultiLocation>;
+		/// The origin of the approvals sent from other chains, e.g. `pallet_xcm::EnsureXcm`
+		type XcmOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = MultiLocation,
+		>;
+		/// The assets whose balances give the vote weight of token weighted supersigs
+		type Assets: fungibles::Inspect<Self::AccountId>;
+		/// The identifier of an NFT collection
+		type CollectionId: Parameter + MaxEncodedLen;
+		/// The identifier of an NFT item
+		type ItemId: Parameter + MaxEncodedLen;
+		/// The NFT collections whose holders are the members of collection backed supersigs
+		type Nfts: nonfungibles_v2::InspectEnumerable<
+			Self::AccountId,
+			CollectionId = Self::CollectionId,
+			ItemId = Self::ItemId,
+		>;
 
+	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	/// The origin of the calls a supersig dispatches with `CallOrigin::Approved`
+	#[pallet::origin]
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum Origin {
+		/// The call has been approved by the members of a supersig. The total weight is the sum of
+		/// the voting weights of all the members
+		/// [supersig_id, approving_weight, total_weight]
+		Approved(SupersigId, u32, u32),
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn nonce_supersig)]
 	pub type NonceSupersig<T: Config> = StorageValue<_, SupersigId, ValueQuery>;
 
+	/// The supersigs that are frozen, with the block they were frozen at
+	#[pallet::storage]
+	#[pallet::getter(fn frozen)]
+	pub type Frozen<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The accounts that can freeze a supersig, on top of its Masters
+	#[pallet::storage]
+	#[pallet::getter(fn guardians)]
+	pub type Guardians<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		BoundedVec<T::AccountId, T::MaxAccountsPerTransaction>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn inheritances)]
+	pub type Inheritances<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		Inheritance<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The block of the last proposal or vote in a supersig
+	#[pallet::storage]
+	#[pallet::getter(fn last_activity)]
+	pub type LastActivity<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, BlockNumberFor<T>, ValueQuery>;
+
+	/// The block the pending inheritance claim of a supersig was made at
+	#[pallet::storage]
+	#[pallet::getter(fn inheritance_claims)]
+	pub type InheritanceClaims<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The id of the supersigs created with a salt, by account
+	#[pallet::storage]
+	#[pallet::getter(fn salted_supersigs)]
+	pub type SaltedSupersigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SupersigId, OptionQuery>;
+
+	/// The account of the supersigs created with a salt, by id
+	#[pallet::storage]
+	#[pallet::getter(fn salted_accounts)]
+	pub type SaltedAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, T::AccountId, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn members)]
 	pub type Members<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, SupersigId, Twox64Concat, T::AccountId, Role, ValueQuery>;
 
+	/// The supersigs each account is an explicit member of. Reverse index of `Members`. It is
+	/// unbounded, so that no one can keep an account out of a supersig by filling its index,
+	/// and is read by pages.
+	#[pallet::storage]
+	pub type AccountSupersigs<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, SupersigId, ()>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn total_members)]
 	pub type TotalMembers<T: Config> = StorageMap<_, Twox64Concat, SupersigId, u32, ValueQuery>;
 
+	/// The number of members with the `Master` role, to check the invariants without reading
+	/// every member
+	#[pallet::storage]
+	#[pallet::getter(fn total_masters)]
+	pub type TotalMasters<T: Config> = StorageMap<_, Twox64Concat, SupersigId, u32, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn total_deposit)]
 	pub type TotalDeposit<T: Config> =
-		StorageMap<_, Twox64Concat, SupersigId, BalanceOf<T>, ValueQuery>;
+		StorageMap<_, Twox64Concat, SupersigId, DepositBalanceOf<T>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn nonce_call)]
@@ -164,10 +424,56 @@ pub mod pallet {
 		SupersigId,
 		Twox64Concat,
 		CallId,
-		PreimageCall<T::AccountId, BalanceOf<T>>,
+		PreimageCall<T::AccountId, DepositBalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The number of vaults of a supersig. Vaults are numbered from 0
+	#[pallet::storage]
+	#[pallet::getter(fn vault_count)]
+	pub type VaultCount<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, VaultIndex, ValueQuery>;
+
+	/// The members of a supersig that can vote from a location on another chain
+	#[pallet::storage]
+	#[pallet::getter(fn remote_voters)]
+	pub type RemoteVoters<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		Blake2_128Concat,
+		MultiLocation,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// The remote locations of each member. Reverse index of `RemoteVoters`, to remove the
+	/// locations of an account that stops being a member.
+	#[pallet::storage]
+	pub type RemoteVoterLocations<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, MultiLocation>,
+		),
+		(),
 		OptionQuery,
 	>;
 
+	/// The origin the calls are dispatched with, when it is not the supersig account
+	#[pallet::storage]
+	#[pallet::getter(fn call_origins)]
+	pub type CallOrigins<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SupersigId, Twox64Concat, CallId, CallOrigin, ValueQuery>;
+
+	/// The approving weight a call needs to be executed, when the proposer asked for more than a
+	/// simple majority
+	#[pallet::storage]
+	#[pallet::getter(fn call_thresholds)]
+	pub type CallThresholds<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SupersigId, Twox64Concat, CallId, u32, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn votes)]
 	pub type Votes<T: Config> =
@@ -188,7 +494,145 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn active_proposals)]
 	pub type ActiveProposals<T: Config> = StorageMap<_, Twox64Concat, SupersigId, u32, ValueQuery>;
-	
+
+	#[pallet::storage]
+	#[pallet::getter(fn invariants)]
+	pub type SupersigInvariants<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, Invariants, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn voting_mode)]
+	pub type VotingModes<T: Config> =
+		StorageMap<_, Twox64Concat, SupersigId, VotingModeOf<T>, ValueQuery>;
+
+	/// Vote weight of each member for a call, taken when the call is proposed.
+	/// Only used by token weighted supersigs.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_weight_snapshots)]
+	pub type VoteWeightSnapshots<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Twox64Concat, CallId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+		),
+		u32,
+		ValueQuery,
+	>;
+
+	/// Items that already voted for a call. Only used by collection backed supersigs, so
+	/// that an item can't vote twice by changing hands.
+	#[pallet::storage]
+	#[pallet::getter(fn item_votes)]
+	pub type ItemVotes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Twox64Concat, CallId>,
+			NMapKey<Twox64Concat, ItemIdOf<T>>,
+		),
+		bool,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		Twox64Concat,
+		T::AccountId,
+		Allowance<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn outflow_limits)]
+	pub type OutflowLimits<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The native currency that left the supersig account at each block of the outflow window,
+	/// oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn outflows)]
+	pub type Outflows<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		BoundedVec<(BlockNumberFor<T>, BalanceOf<T>), T::MaxOutflowBuckets>,
+		ValueQuery,
+	>;
+
+	/// Tally of the votes of the members of a child supersig for a call of its parent.
+	#[pallet::storage]
+	#[pallet::getter(fn child_votes)]
+	pub type ChildVotes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Twox64Concat, CallId>,
+			NMapKey<Twox64Concat, SupersigId>,
+		),
+		u32,
+		ValueQuery,
+	>;
+
+	/// Votes of the members of a child supersig for a call of its parent.
+	#[pallet::storage]
+	#[pallet::getter(fn child_members_votes)]
+	pub type ChildMembersVotes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Twox64Concat, CallId>,
+			NMapKey<Twox64Concat, SupersigId>,
+			NMapKey<Twox64Concat, T::AccountId>,
+		),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Number of the votes a member of a child supersig cast for calls of its parents that are
+	/// still pending. The member can't be rotated while it has some.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_child_votes)]
+	pub type PendingChildVotes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Sum of the snapshotted vote weights of a call.
+	/// Only used by token weighted and collection backed supersigs, for which it is the number of
+	/// items of the collection.
+	#[pallet::storage]
+	#[pallet::getter(fn total_weight_snapshots)]
+	pub type TotalWeightSnapshots<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SupersigId, Twox64Concat, CallId, u32, ValueQuery>;
+
+	/// The items of the collection when a call was proposed, the only ones that can vote for it.
+	/// Only used by collection backed supersigs.
+	#[pallet::storage]
+	#[pallet::getter(fn item_snapshots)]
+	pub type ItemSnapshots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SupersigId,
+		Twox64Concat,
+		CallId,
+		BoundedVec<ItemIdOf<T>, T::MaxSnapshotSize>,
+		ValueQuery,
+	>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -212,6 +656,49 @@ pub mod pallet {
 		MembersRemoved(T::AccountId, Vec<T::AccountId>),
 		/// a member left the supersig [supersig, member]
 		SupersigLeft(T::AccountId, T::AccountId),
+		/// a member has been replaced by a new account [supersig, old_member, new_member]
+		MemberRotated(T::AccountId, T::AccountId, T::AccountId),
+		/// the invariants of the supersig have been updated [supersig, invariants]
+		InvariantsSet(T::AccountId, Invariants),
+		/// the voting mode of the supersig has been updated [supersig, voting_mode]
+		VotingModeSet(T::AccountId, VotingModeOf<T>),
+		/// a member of a child supersig voted for a call of the parent
+		/// [child_supersig, parent_supersig, call_nonce, voter]
+		ChildCallVoted(T::AccountId, T::AccountId, CallId, T::AccountId),
+		/// the allowance of a member has been set, a zero amount removes it
+		/// [supersig, member, amount, period]
+		AllowanceSet(T::AccountId, T::AccountId, BalanceOf<T>, BlockNumberFor<T>),
+		/// a member spent some of its allowance [supersig, member, destination, amount]
+		AllowanceSpent(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// the outflow limit of the supersig has been set or removed [supersig, limit]
+		OutflowLimitSet(
+			T::AccountId,
+			Option<OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+		),
+		/// the guardians of the supersig have been set [supersig, guardians]
+		GuardiansSet(T::AccountId, Vec<T::AccountId>),
+		/// the supersig has been frozen [supersig, frozen by]
+		SupersigFrozen(T::AccountId, T::AccountId),
+		/// the supersig has been unfrozen [supersig]
+		SupersigUnfrozen(T::AccountId),
+		/// the inheritance of the supersig has been set or removed [supersig, inheritance]
+		InheritanceSet(
+			T::AccountId,
+			Option<Inheritance<T::AccountId, BlockNumberFor<T>>>,
+		),
+		/// the beneficiary claimed the supersig [supersig, beneficiary]
+		InheritanceClaimed(T::AccountId, T::AccountId),
+		/// a member cancelled the inheritance claim [supersig, member]
+		InheritanceClaimCancelled(T::AccountId, T::AccountId),
+		/// the supersig has been removed in favor of the beneficiary [supersig, beneficiary]
+		InheritanceExecuted(T::AccountId, T::AccountId),
+		/// a vault has been added to the supersig [supersig, vault_index, vault_account]
+		VaultAdded(T::AccountId, VaultIndex, T::AccountId),
+		/// the supersig sent an XCM message [supersig, destination, message_id]
+		XcmSent(T::AccountId, MultiLocation, XcmHash),
+		/// the member a remote location votes for has been set or removed
+		/// [supersig, location, member]
+		RemoteVoterSet(T::AccountId, MultiLocation, Option<T::AccountId>),
 	}
 
 	#[pallet::error]
@@ -244,6 +731,67 @@ pub mod pallet {
 		CallDataTooLarge,
 		/// Too many active proposals for the given supersig. Proposal voting needs to be completed before another can be proposed. 
 		TooManyActiveProposals,
+		/// the account is already a member of the supersig
+		AlreadyMember,
+		/// the membership change would break the invariants of the supersig
+		InvariantsViolated,
+		/// the operation can't be performed while the supersig has live proposals
+		ProposalsPending,
+		/// the supersig would become one of its own members
+		MembershipCycle,
+		/// the supersigs are nested deeper than MaxNestingDepth, or have more than MaxNestingVisits
+		/// members in total
+		NestingTooDeep,
+		/// the voting mode of the supersig doesn't support this operation
+		UnsupportedVotingMode,
+		/// an allowance period can't be zero
+		InvalidPeriod,
+		/// the member has no allowance on this supersig
+		NoAllowance,
+		/// the amount exceeds what is left of the allowance for the current period
+		AllowanceExceeded,
+		/// executing the call would move more funds out of the supersig than its outflow limit
+		/// allows for the current window. The call stays queued
+		OutflowLimitExceeded,
+		/// a supersig already exists for this creator and salt
+		SaltAlreadyUsed,
+		/// the supersig is frozen, only a call to `unfreeze` can be proposed and approved
+		SupersigFrozen,
+		/// the supersig is not frozen
+		NotFrozen,
+		/// the supersig has no inheritance, or the caller is not its beneficiary
+		NoInheritance,
+		/// the members of the supersig have not been inactive for long enough
+		SupersigActive,
+		/// an inheritance claim is already pending
+		ClaimPending,
+		/// there is no pending inheritance claim
+		NoClaim,
+		/// the challenge period of the inheritance claim is not over
+		ChallengePeriodNotOver,
+		/// the supersig already has MaxVaults vaults
+		TooManyVaults,
+		/// the supersig has no vault with this index
+		VaultNotFound,
+		/// the XCM destination or message version can't be converted to the latest one
+		BadXcmVersion,
+		/// the XCM message couldn't be sent to the destination
+		XcmSendFailed,
+		/// the supersig can't pay the price of delivering the XCM message
+		FeesNotMet,
+		/// the location is not allowed to vote for a member of the supersig
+		UnknownRemoteVoter,
+		/// the threshold is higher than the total voting weight of the supersig
+		ThresholdTooHigh,
+		/// the supersig has more than MaxSnapshotSize members, or its collection more than
+		/// MaxSnapshotSize items
+		SnapshotTooLarge,
+		/// none of the members of the token weighted supersig holds the asset
+		NoAssetHolder,
+		/// the call weighs more than MaxCallWeight
+		CallTooHeavy,
+		/// the member voted, as a member of a child supersig, for calls of a parent still pending
+		ChildVotesPending,
 	}
 
 	#[pallet::call]
@@ -255,7 +803,8 @@ pub mod pallet {
 		/// incrementing nonce. The caller will be assigned the "Master" role, allowing them to add or remove
 		/// members and manage the supersig's extrinsic calls.
 		///
-		/// A deposit is required to create a supersig, which will be reserved from the caller's account.
+		/// A deposit is required to create a supersig, which will be escrowed from the supersig
+		/// account.
 		/// This deposit acts as a security measure to prevent spam and abuse of the network. The deposit can
 		/// be partially or fully returned when members are removed or the supersig is deleted.
 		///
@@ -271,55 +820,29 @@ pub mod pallet {
 		/// # <weight>
 		///
 		/// Related functions:
-		/// - `Currency::transfer` will be called once to deposit an existencial amount on supersig
-		/// - `frame_system::inc_consumers` will be called once to protect the supersig from
+		/// - `DepositAssets::transfer` will be called once to deposit the greater of an
+		///   existencial amount and the deposit on supersig, and once to escrow the deposit
+		/// - `frame_system::inc_providers` will be called once to protect the supersig from
 		///   deletion
 		#[pallet::call_index(0)]
 		#[transactional]
-		#[pallet::weight(T::WeightInfo::create_supersig(members.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::create_supersig(members.len() as u32)
+				.saturating_add(Pallet::<T>::cycle_check_weight(members.len() as u32))
+		)]
 		pub fn create_supersig(
 			origin: OriginFor<T>,
 			members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			// A supersig should at least have one member
-			let member_length = members.len();
-			if member_length < 1 {
-				return Err(Error::<T>::MustHaveAtLeastOneMember.into())
-			}
-
 			// Get id and associated account
 			let supersig_id = Self::nonce_supersig();
 			let supersig_account: T::AccountId = T::PalletId::get()
 				.try_into_sub_account(supersig_id)
 				.ok_or(Error::<T>::InvalidNonce)?;
 
-			// Update Members and TotalMembers storages
-			let added_members = Self::internal_add_members(supersig_id, members)?;
-
-			// Bring account to existence
-			let deposit = Self::compute_deposit(size_of::<T::AccountId>() * added_members.len())?;
-			let amount_to_transfer = max(T::Currency::minimum_balance(), deposit);
-			T::Currency::transfer(
-				&who,
-				&supersig_account,
-				amount_to_transfer,
-				ExistenceRequirement::AllowDeath,
-			)?;
-
-			// Prevent the supersig account to sign transaction that would kill it
-			frame_system::Pallet::<T>::inc_consumers(&supersig_account)?;
-
-			// Incentive to delete supersigs that are no longer used
-			Self::reserve_and_record_deposit(supersig_id, &supersig_account, deposit)?;
-
-			NonceSupersig::<T>::put(supersig_id + 1);
-
-			Self::deposit_event(Event::<T>::SupersigCreated(supersig_account.clone()));
-			Self::deposit_event(Event::<T>::MembersAdded(supersig_account, added_members));
-
-			Ok(())
+			Self::do_create_supersig(&who, supersig_id, supersig_account, members)
 		}
 
 		/// Propose Call
@@ -330,7 +853,7 @@ pub mod pallet {
 		/// supersig members, and the execution of the call is subject to the approval threshold.
 		///
 		/// The call to be executed is provided as a pre-image, which will be stored on-chain for the 
-		/// during of the voting process. A deposit is required to propose a call, which will be reserved 
+		/// during of the voting process. A deposit is required to propose a call, which will be escrowed 
 		/// from the proposer's account. This deposit serves as a security measure to prevent spam and abuse 
 		/// of the network. The deposit can be partially or fully returned when the call is executed or removed.
 		///
@@ -347,50 +870,29 @@ pub mod pallet {
 		/// # <weight>
 		///
 		/// Related functions:
-		/// - `Currency::reserve` will be called once to lock the deposit amount
+		/// - `DepositAssets::transfer` will be called once to escrow the deposit amount
+		/// - the asset balances of the members of a token weighted supersig, or the items of the
+		///   collection of a collection backed supersig, are snapshotted, at most MaxSnapshotSize
+		///   of them. The weight of the ones not snapshotted is refunded
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::propose_call(call.encode().len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::propose_call(call.encode().len() as u32)
+				.saturating_add(Pallet::<T>::snapshot_weight(T::MaxSnapshotSize::get()))
+		)]
 		pub fn propose_call(
 			origin: OriginFor<T>,
 			supersig_account: T::AccountId,
 			call: Box<<T as pallet::Config>::Call>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
-
-			let data = call.encode();
-
-			ensure!(
-				data.len() <= T::MaxCallDataSize::get() as usize,
-				Error::<T>::CallDataTooLarge
-			);
-
-			// Modify the propose_call extrinsic to check the number of active proposals before allowing a new one
-			let current_active_proposals = Self::active_proposals(supersig_id);
-    		ensure!(
-				current_active_proposals < T::MaxCallsPerAccount::get(), Error::<T>::TooManyActiveProposals);
-			
-			// Increment the number of active proposals for the Supersig account when a new proposal is submitted
-			ActiveProposals::<T>::mutate(supersig_id, |active_proposals| *active_proposals += 1);
-
-			// Incentive to remove proposal that won't be accepted
-			let deposit = Self::compute_deposit(data.len())?;
-			T::Currency::reserve(&who, deposit)?;
-
-			// The encoded call is stored, along with the infos needed to unreserve the funds
-			// associated with it
-			let call_id = Self::nonce_call(supersig_id);
-			NonceCall::<T>::insert(supersig_id, call_id + 1);
-			let preimage = PreimageCall::<T::AccountId, BalanceOf<T>> {
-				data,
-				provider: who.clone(),
-				deposit,
-			};
-			Calls::<T>::insert(supersig_id, call_id, preimage);
-
-			Self::deposit_event(Event::<T>::CallSubmitted(supersig_account, call_id, who));
-
-			Ok(())
+			let call_len = call.encode().len() as u32;
+			let snapshot_size =
+				Self::do_propose_call(who, supersig_account, call, CallOrigin::Signed, None)?;
+			Ok(Some(
+				T::WeightInfo::propose_call(call_len)
+					.saturating_add(Self::snapshot_weight(snapshot_size)),
+			)
+			.into())
 		}
 
 		/// Approve Call (Vote)
@@ -415,70 +917,26 @@ pub mod pallet {
 		/// # <weight>
 		///
 		/// Related functions:
-		/// - `Currency::unreserve` will be called once IF SimpleMajority is reached
+		/// - `Currency::release` will be called once IF SimpleMajority is reached
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::approve_call())]
+		#[transactional]
+		#[pallet::weight(
+			Pallet::<T>::approval_weight(
+				T::WeightInfo::approve_call(),
+				Some(T::MaxCallWeight::get()),
+			)
+		)]
 		pub fn approve_call(
 			origin: OriginFor<T>,
 			supersig_account: T::AccountId,
 			call_id: CallId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
 
-			if Self::calls(supersig_id, call_id).is_none() {
-				return Err(Error::<T>::CallNotFound.into())
-			}
-			if Self::members_votes((supersig_id, call_id, who.clone())) {
-				return Err(Error::<T>::AlreadyVoted.into())
-			}
-
-			// Different roles have different voting weight
-			let vote_weight = Self::compute_vote_weight(supersig_id, &who)?;
-
-			// Update storage with the user vote
-			MembersVotes::<T>::insert((supersig_id, call_id, who.clone()), true);
-			Votes::<T>::mutate(supersig_id, call_id, |val| {
-				*val = val.saturating_add(vote_weight)
-			});
-			
-
-			Self::deposit_event(Event::<T>::CallVoted(
-				supersig_account.clone(),
-				call_id,
-				who,
-			));
-
-			let total_votes = Self::votes(supersig_id, call_id);
-			if total_votes >= (Self::total_members(supersig_id) / 2 + 1) {
-				if let Some(preimage) = Self::calls(supersig_id, call_id) {
-					// free storage and unreserve deposit
-					Self::unchecked_remove_call_from_storages(supersig_id, call_id);
-					T::Currency::unreserve(&preimage.provider, preimage.deposit);
-
-					// Decrement the number of active proposals when the proposal is approved or rejected, freeing up space for a new live proposal. 
-					ActiveProposals::<T>::mutate(supersig_id, |active_proposals| *active_proposals = active_proposals.saturating_sub(1));
-
-					// Try to decode and execute the call
-					let res = if let Ok(call) = <T as Config>::Call::decode(&mut &preimage.data[..])
-					{
-						call.dispatch(
-							frame_system::RawOrigin::Signed(supersig_account.clone()).into(),
-						)
-					} else {
-						Err(Error::<T>::BadEncodedCall.into())
-					};
-
-					Self::deposit_event(Event::<T>::CallExecutionAttempted(
-						supersig_account,
-						call_id,
-						res,
-					));
-				}
-			}
-
-			Ok(())
-		}
+			let call_weight = Self::do_approve_call(supersig_id, supersig_account, call_id, who)?;
+			Ok(Some(Self::approval_weight(T::WeightInfo::approve_call(), call_weight)).into())
+		}
 
 		/// remove a call from the supersig.
 		///
@@ -490,7 +948,7 @@ pub mod pallet {
 		/// # <weight>
 		///
 		/// Related functions:
-		/// - `Currency::unreserve` will be called once
+		/// - `Currency::release` will be called once
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::remove_call())]
 		pub fn remove_call(
@@ -507,9 +965,9 @@ pub mod pallet {
 				return Err(Error::<T>::NotAllowed.into())
 			}
 
-			// Clean up storage and release reserved funds
+			// Clean up storage and release escrowed funds
 			Self::unchecked_remove_call_from_storages(supersig_id, call_id);
-			T::Currency::unreserve(&preimage.provider, preimage.deposit);
+			Self::release_proposal_deposit(&preimage)?;
 
 			// Decrement the number of active proposals when the call is removed, freeing up space for a new live proposal call. 
 			ActiveProposals::<T>::mutate(supersig_id, |active_proposals| *active_proposals = active_proposals.saturating_sub(1));
@@ -530,7 +988,10 @@ pub mod pallet {
 		/// # <weight>
 		#[pallet::call_index(4)]
 		#[transactional]
-		#[pallet::weight(T::WeightInfo::add_members(new_members.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::add_members(new_members.len() as u32)
+				.saturating_add(Pallet::<T>::cycle_check_weight(new_members.len() as u32))
+		)]
 		pub fn add_members(
 			origin: OriginFor<T>,
 			new_members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
@@ -543,7 +1004,7 @@ pub mod pallet {
 
 			// Incentive to delete supersigs that are no longer used
 			let deposit = Self::compute_deposit(size_of::<T::AccountId>() * added_members.len())?;
-			Self::reserve_and_record_deposit(supersig_id, &supersig_account, deposit)?;
+			Self::escrow_and_record_deposit(supersig_id, &supersig_account, deposit)?;
 
 			Self::deposit_event(Event::<T>::MembersAdded(supersig_account, added_members));
 
@@ -582,7 +1043,7 @@ pub mod pallet {
 			)?;
 
 			// Release a proportional amount of deposit
-			Self::unreserve_and_record_deposit(supersig_id, &supersig_account, amount_to_unreserve);
+			Self::refund_and_record_deposit(supersig_id, &supersig_account, amount_to_unreserve)?;
 
 			Self::deposit_event(Event::<T>::MembersRemoved(
 				supersig_account,
@@ -594,9 +1055,9 @@ pub mod pallet {
 
 		/// remove the supersig
 		///
-		/// `delete_supersig` will remove every members, transfer every remanent funds to the
-		/// target account, remove the supersig from storage, and set the consumers and providers
-		/// to 0
+		/// `delete_supersig` will remove every members, transfer every remanent funds, those of
+		/// the vaults included, to the target account, remove the supersig from storage, and set
+		/// the consumers and providers to 0
 		///
 		/// The dispatch origin for this call must be `Signed` by the supersig
 		///
@@ -608,33 +1069,7 @@ pub mod pallet {
 			let supersig_account = ensure_signed(origin)?;
 			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
 
-			// Release all member related deposits
-			let total_deposit = TotalDeposit::<T>::take(supersig_id);
-			T::Currency::unreserve(&supersig_account, total_deposit);
-
-			// Release all call related deposits
-			Calls::<T>::iter_prefix_values(supersig_id).for_each(|preimage| {
-				T::Currency::unreserve(&preimage.provider, preimage.deposit);
-			});
-
-			// Erase trace of this supersis in storage and decrement the account reference counter
-			Self::unchecked_remove_supersig_from_storages(supersig_id, &supersig_account);
-
-			// Empty the supersig account balance
-			// Will cause death of the account
-			// Will fail and revert the transaction if this account is not allowed to die
-			// due to an other pallet reference counter, reserved or frozen funds
-			T::Currency::transfer(
-				&supersig_account,
-				&beneficiary,
-				T::Currency::total_balance(&supersig_account),
-				ExistenceRequirement::AllowDeath,
-			)
-			.map_err(|_| Error::<T>::SupersigHaveLockedFunds)?;
-
-			Self::deposit_event(Event::<T>::SupersigRemoved(supersig_account));
-
-			Ok(())
+			Self::do_delete_supersig(supersig_id, supersig_account, &beneficiary)
 		}
 
 		/// Leave a supersig.
@@ -655,6 +1090,7 @@ pub mod pallet {
 		///
 		/// # <weight>
 		#[pallet::call_index(7)]
+		#[transactional]
 		#[pallet::weight(T::WeightInfo::leave_supersig())]
 		pub fn leave_supersig(
 			origin: OriginFor<T>,
@@ -662,6 +1098,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			Self::ensure_not_frozen(supersig_id)?;
 
 			if Self::members(supersig_id, &who) == Role::NotMember {
 				return Err(Error::<T>::NotMember.into())
@@ -688,156 +1125,1842 @@ pub mod pallet {
 			})?;
 
 			// Note that the votes the user made stays in storage
+			let role = Self::members(supersig_id, &who);
+			Self::record_role_change(supersig_id, &role, &Role::NotMember);
 			Members::<T>::remove(supersig_id, &who);
+			Self::unindex_member(supersig_id, &who);
+			Allowances::<T>::remove(supersig_id, &who);
+			Self::remove_remote_voters(supersig_id, &who);
+			Self::ensure_invariants(supersig_id)?;
 
 			// Release a proportional amount of deposit
-			Self::unreserve_and_record_deposit(supersig_id, &supersig_account, amount_to_unreserve);
+			Self::refund_and_record_deposit(supersig_id, &supersig_account, amount_to_unreserve)?;
 
 			Self::deposit_event(Event::<T>::SupersigLeft(supersig_account, who));
 
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		pub fn get_supersig_id_from_account(
-			supersig_account: &T::AccountId,
-		) -> Result<SupersigId, pallet::Error<T>> {
-			if let Some((account, supersig_id)) = PalletId::try_from_sub_account(supersig_account) {
-				if account != T::PalletId::get() || Self::total_members(supersig_id) == 0 {
-					return Err(Error::<T>::NotSupersig)
-				}
-				Ok(supersig_id)
-			} else {
-				Err(Error::<T>::NotSupersig)
+		/// Rotate a member key.
+		///
+		/// `rotate_member` will replace `old_member` by `new_member` in the supersig. The new
+		/// account gets the role of the old one, and every vote the old account cast on a pending
+		/// call is moved to the new account. `TotalMembers`, `TotalDeposit` and the tally of the
+		/// calls are left untouched.
+		/// A member that voted with `approve_call_as_child` for calls of a parent supersig can't
+		/// be rotated until those calls are executed or removed, or the child voted for them.
+		///
+		/// The dispatch origin for this call must be `Signed` by either the supersig or the member
+		/// being rotated
+		///
+		/// # <weight>
+		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::rotate_member(T::MaxCallsPerAccount::get())
+				.saturating_add(Pallet::<T>::cycle_check_weight(1))
+		)]
+		pub fn rotate_member(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+			old_member: T::AccountId,
+			new_member: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			Self::ensure_not_frozen(supersig_id)?;
+
+			// Either the supersig or the member itself can rotate its key
+			if who != supersig_account && who != old_member {
+				return Err(Error::<T>::NotAllowed.into())
 			}
-		}
 
-		fn unchecked_remove_call_from_storages(supersig_id: SupersigId, call_id: CallId) {
-			Calls::<T>::remove(supersig_id, call_id);
-			Votes::<T>::remove(supersig_id, call_id);
-			let _ = MembersVotes::<T>::clear_prefix((supersig_id, call_id), u32::MAX, None);
+			let role = Self::members(supersig_id, &old_member);
+			if role == Role::NotMember {
+				return Err(Error::<T>::NotMember.into())
+			}
+			if Self::members(supersig_id, &new_member) != Role::NotMember {
+				return Err(Error::<T>::AlreadyMember.into())
+			}
+			// Its votes for the calls of the parents can't be found to be moved
+			if Self::pending_child_votes(supersig_id, &old_member) > 0 {
+				return Err(Error::<T>::ChildVotesPending.into())
+			}
+			Self::ensure_no_cycle(supersig_id, &new_member)?;
+
+			Members::<T>::remove(supersig_id, &old_member);
+			Members::<T>::insert(supersig_id, &new_member, role);
+			Self::unindex_member(supersig_id, &old_member);
+			Self::index_member(supersig_id, &new_member);
+			if let Some(allowance) = Allowances::<T>::take(supersig_id, &old_member) {
+				Allowances::<T>::insert(supersig_id, &new_member, allowance);
+			}
+			Self::remove_remote_voters(supersig_id, &old_member);
+
+			// Move the votes and vote weights on the pending calls, the tally stays the same
+			Calls::<T>::iter_key_prefix(supersig_id).for_each(|call_id| {
+				if MembersVotes::<T>::take((supersig_id, call_id, old_member.clone())) {
+					MembersVotes::<T>::insert((supersig_id, call_id, new_member.clone()), true);
+				}
+				let weight =
+					VoteWeightSnapshots::<T>::take((supersig_id, call_id, old_member.clone()));
+				if weight > 0 {
+					VoteWeightSnapshots::<T>::insert(
+						(supersig_id, call_id, new_member.clone()),
+						weight,
+					);
+				}
+			});
+
+			Self::deposit_event(Event::<T>::MemberRotated(
+				supersig_account,
+				old_member,
+				new_member,
+			));
+
+			Ok(())
 		}
 
-		fn unchecked_remove_supersig_from_storages(
-			supersig_id: SupersigId,
-			supersig_account: &T::AccountId,
-		) {
-			NonceCall::<T>::remove(supersig_id);
-			let _ = Members::<T>::clear_prefix(supersig_id, u32::MAX, None);
-			TotalMembers::<T>::remove(supersig_id);
-			let _ = Calls::<T>::clear_prefix(supersig_id, u32::MAX, None);
-			let _ = Votes::<T>::clear_prefix(supersig_id, u32::MAX, None);
-			let _ = MembersVotes::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+		/// Set the invariants of the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `set_invariants` will store the minimum number of Masters and the minimum total vote
+		/// weight the supersig must keep. Any later `add_members`, `remove_members` or
+		/// `leave_supersig` that would break them is rejected with `InvariantsViolated`.
+		/// The current members must already satisfy the new invariants.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_invariants())]
+		pub fn set_invariants(origin: OriginFor<T>, invariants: Invariants) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			SupersigInvariants::<T>::insert(supersig_id, invariants.clone());
+			Self::ensure_invariants(supersig_id)?;
 
-			frame_system::Pallet::<T>::dec_consumers(supersig_account);
+			Self::deposit_event(Event::<T>::InvariantsSet(supersig_account, invariants));
+
+			Ok(())
 		}
 
-		fn internal_add_members(
-			supersig_id: SupersigId,
-			members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
-		) -> Result<Vec<(T::AccountId, Role)>, Error<T>> {
-			let mut added = Vec::new();
+		/// Set the voting mode of the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `set_voting_mode` will choose how the vote weight of the members is computed:
+		///     - `Role`: a Standard member weights 1, a Master weights half the members
+		///     - `Token(asset)`: a member weights its share of `asset` among all the members. The
+		///       balances are snapshotted when a call is proposed, and the call is executed once
+		///       the members holding more than half of the snapshotted balances approved it
+		///     - `Collection(collection)`: the holders of the items of `collection` are the
+		///       members, whatever the `Members` storage contains. Each item is worth one vote,
+		///       and the call is executed once more than half of the items approved it
+		///
+		/// The mode can't be changed while the supersig has live proposals.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_voting_mode())]
+		pub fn set_voting_mode(
+			origin: OriginFor<T>,
+			voting_mode: VotingModeOf<T>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
 
-			for (member, role) in members {
-				if Self::members(supersig_id, &member) == Role::NotMember {
-					added.push((member.clone(), role.clone()));
-				}
-				Members::<T>::insert(supersig_id, member, role);
+			// The call dispatching this one has already been removed from the live proposals
+			ensure!(Self::active_proposals(supersig_id) == 0, Error::<T>::ProposalsPending);
+			// A supersig created from a collection has no explicit member to fall back on
+			if !matches!(voting_mode, VotingMode::Collection(_)) {
+				ensure!(Self::total_members(supersig_id) > 0, Error::<T>::MustHaveAtLeastOneMember);
 			}
 
-			TotalMembers::<T>::try_mutate(supersig_id, |n| {
-				*n = n
-					.checked_add(added.len().try_into().map_err(|_| Error::<T>::Conversion)?)
-					.ok_or(Error::<T>::Overflow)?;
+			VotingModes::<T>::insert(supersig_id, voting_mode.clone());
 
-				Ok(())
-			})?;
+			Self::deposit_event(Event::<T>::VotingModeSet(supersig_account, voting_mode));
 
-			Ok(added)
+			Ok(())
 		}
 
-		fn internal_remove_members(
-			supersig_id: SupersigId,
-			members: BoundedVec<T::AccountId, T::MaxAccountsPerTransaction>,
-		) -> Result<Vec<T::AccountId>, pallet::Error<T>> {
-			let mut removed = Vec::new();
+		/// Create a supersig backed by an NFT collection.
+		///
+		/// `create_supersig_from_collection` will create a supersig in the `Collection` voting
+		/// mode: its members are whoever holds an item of `collection`, and transferring an item
+		/// transfers the seat. No explicit member is added.
+		///
+		/// The creator transfers to the supersig the existential deposit and the price of the
+		/// stored voting mode times the DepositPerByte, the later being escrowed from the supersig.
+		///
+		/// The dispatch origin for this call must be `Signed`.
+		///
+		/// # <weight>
+		///
+		/// Related functions:
+		/// - `DepositAssets::transfer` will be called once to deposit the greater of an
+		///   existencial amount and the deposit on supersig, and once to escrow the deposit
+		/// - `frame_system::inc_providers` will be called once to protect the supersig from
+		///   deletion
+		#[pallet::call_index(11)]
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::create_supersig_from_collection())]
+		pub fn create_supersig_from_collection(
+			origin: OriginFor<T>,
+			collection: CollectionIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			for member in members {
-				if Self::members(supersig_id, &member) != Role::NotMember {
-					Members::<T>::remove(supersig_id, member.clone());
-					removed.push(member);
-				}
-			}
+			// Get id and associated account
+			let supersig_id = Self::nonce_supersig();
+			let supersig_account: T::AccountId = T::PalletId::get()
+				.try_into_sub_account(supersig_id)
+				.ok_or(Error::<T>::InvalidNonce)?;
 
-			TotalMembers::<T>::try_mutate(supersig_id, |n| {
-				let new_total_members =
-					n.saturating_sub(removed.len().try_into().map_err(|_| Error::<T>::Conversion)?);
-				if new_total_members < 1 {
-					return Err(Error::<T>::MustHaveAtLeastOneMember)
-				}
+			let voting_mode = VotingMode::Collection(collection);
+			VotingModes::<T>::insert(supersig_id, voting_mode.clone());
 
-				*n = new_total_members;
+			// Bring account to existence
+			let deposit = Self::compute_deposit(VotingModeOf::<T>::max_encoded_len())?;
+			Self::open_supersig_account(&who, supersig_id, &supersig_account, deposit)?;
 
-				Ok(())
-			})?;
+			NonceSupersig::<T>::put(supersig_id + 1);
 
-			Ok(removed)
+			Self::deposit_event(Event::<T>::SupersigCreated(supersig_account.clone()));
+			Self::deposit_event(Event::<T>::VotingModeSet(supersig_account, voting_mode));
+
+			Ok(())
 		}
 
-		fn compute_deposit(data_size: usize) -> Result<BalanceOf<T>, Error<T>> {
-			let bytes_stored: u32 = data_size.try_into().map_err(|_| Error::<T>::Conversion)?;
+		/// Approve a call of a parent supersig through a child supersig.
+		///
+		/// `approve_call_as_child` will add the vote of a member of `child_account` for the call
+		/// `call_id` of `parent_account`, `child_account` being a member of `parent_account`.
+		/// When the votes of the child members reach the child threshold, the child supersig
+		/// votes for the call in the parent, which may execute it.
+		///
+		/// The child supersig must use the `Role` voting mode.
+		///
+		/// The dispatch origin for this call must be `Signed`, and the origin must be a
+		/// member of the child supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(12)]
+		#[transactional]
+		#[pallet::weight(
+			Pallet::<T>::approval_weight(
+				T::WeightInfo::approve_call_as_child(),
+				Some(T::MaxCallWeight::get()),
+			)
+		)]
+		pub fn approve_call_as_child(
+			origin: OriginFor<T>,
+			child_account: T::AccountId,
+			parent_account: T::AccountId,
+			call_id: CallId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let child_id = Self::get_supersig_id_from_account(&child_account)?;
+			let parent_id = Self::get_supersig_id_from_account(&parent_account)?;
+			Self::ensure_not_frozen(child_id)?;
+
+			let preimage = Self::calls(parent_id, call_id).ok_or(Error::<T>::CallNotFound)?;
+			Self::ensure_can_execute(parent_id, &preimage.data)?;
+			if !Self::is_member(parent_id, &child_account) {
+				return Err(Error::<T>::NotMember.into())
+			}
+			if Self::voting_mode(child_id) != VotingMode::Role {
+				return Err(Error::<T>::UnsupportedVotingMode.into())
+			}
+			if Self::child_members_votes((parent_id, call_id, child_id, who.clone())) {
+				return Err(Error::<T>::AlreadyVoted.into())
+			}
+
+			let vote_weight = Self::weight_of_role(
+				&Self::members(child_id, &who),
+				Self::total_members(child_id),
+			)
+			.ok_or(Error::<T>::NotMember)?;
+
+			ChildMembersVotes::<T>::insert((parent_id, call_id, child_id, who.clone()), true);
+			PendingChildVotes::<T>::mutate(child_id, &who, |count| {
+				*count = count.saturating_add(1)
+			});
+			let child_votes = ChildVotes::<T>::mutate((parent_id, call_id, child_id), |val| {
+				*val = val.saturating_add(vote_weight);
+				*val
+			});
+
+			Self::deposit_event(Event::<T>::ChildCallVoted(
+				child_account.clone(),
+				parent_account.clone(),
+				call_id,
+				who,
+			));
+
+			let mut call_weight = None;
+			if child_votes >= Self::simple_majority(Self::total_members(child_id)) {
+				ChildVotes::<T>::remove((parent_id, call_id, child_id));
+				ChildMembersVotes::<T>::drain_prefix((parent_id, call_id, child_id))
+					.for_each(|(member, _)| Self::uncount_child_vote(child_id, &member));
+
+				call_weight =
+					Self::do_approve_call(parent_id, parent_account, call_id, child_account)?;
+			}
 
-			Ok(<BalanceOf<T>>::from(bytes_stored).saturating_mul(T::DepositPerByte::get()))
+			Ok(Some(Self::approval_weight(T::WeightInfo::approve_call_as_child(), call_weight))
+				.into())
 		}
 
-		// This function can fail after a storage mutation.
-		// extrinsics that use it should have the #[transactional] annotation.
-		fn reserve_and_record_deposit(
-			supersig_id: SupersigId,
-			supersig_account: &T::AccountId,
-			deposit: BalanceOf<T>,
+		/// Set the allowance of a member. You need to wrap this in a proposeCall function.
+		///
+		/// `set_allowance` will let `member` spend up to `amount` of native currency from the
+		/// supersig account every `period` blocks, with `spend_allowance`, without a vote.
+		/// The current period starts now, with the full amount available.
+		/// A zero `amount` removes the allowance.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_allowance())]
+		pub fn set_allowance(
+			origin: OriginFor<T>,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+			period: BlockNumberFor<T>,
 		) -> DispatchResult {
-			T::Currency::reserve(supersig_account, deposit)?;
-			TotalDeposit::<T>::try_mutate(supersig_id, |val| {
-				*val = val.checked_add(&deposit).ok_or(Error::<T>::Overflow)?;
-				Ok(())
-			})
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			if amount.is_zero() {
+				Allowances::<T>::remove(supersig_id, &member);
+			} else {
+				ensure!(Self::is_member(supersig_id, &member), Error::<T>::NotMember);
+				ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+				let allowance = Allowance {
+					amount,
+					period,
+					remaining: amount,
+					period_start: frame_system::Pallet::<T>::block_number(),
+				};
+				Allowances::<T>::insert(supersig_id, &member, allowance);
+			}
+
+			Self::deposit_event(Event::<T>::AllowanceSet(supersig_account, member, amount, period));
+
+			Ok(())
 		}
 
-		fn unreserve_and_record_deposit(
-			supersig_id: SupersigId,
-			supersig_account: &T::AccountId,
+		/// Spend from an allowance.
+		///
+		/// `spend_allowance` will transfer `amount` of native currency from the supersig account
+		/// to `dest`, and deduct it from the caller allowance for the current period. When the
+		/// period is over, a new one starts with the full amount available.
+		/// The supersig account is kept alive. The amount counts towards the outflow limit of the
+		/// supersig, like the funds moved by executed calls.
+		///
+		/// The dispatch origin for this call must be `Signed`, and the origin must be a
+		/// supersig's member with an allowance
+		///
+		/// # <weight>
+		///
+		/// Related functions:
+		/// - `Currency::transfer` will be called once to send the funds
+		#[pallet::call_index(14)]
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::spend_allowance())]
+		pub fn spend_allowance(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+			dest: T::AccountId,
 			amount: BalanceOf<T>,
-		) {
-			T::Currency::unreserve(supersig_account, amount);
-			TotalDeposit::<T>::mutate(supersig_id, |val| *val = val.saturating_sub(amount));
-		}
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			Self::ensure_not_frozen(supersig_id)?;
 
-		fn compute_vote_weight(
-			supersig_id: SupersigId,
-			who: &T::AccountId,
-		) -> Result<u32, Error<T>> {
-			match Self::members(supersig_id, who) {
-				Role::Standard => Ok(1),
-				Role::Master => Ok(max(Self::total_members(supersig_id) / 2, 1)),
-				Role::NotMember => Err(Error::<T>::NotMember),
-			}
+			ensure!(Self::is_member(supersig_id, &who), Error::<T>::NotMember);
+			let allowance =
+				Self::current_allowance(supersig_id, &who).ok_or(Error::<T>::NoAllowance)?;
+			let remaining =
+				allowance.remaining.checked_sub(&amount).ok_or(Error::<T>::AllowanceExceeded)?;
+
+			Self::record_outflow(supersig_id, amount)?;
+			T::Currency::transfer(
+				&supersig_account,
+				&dest,
+				amount,
+				Preservation::Preserve,
+			)?;
+			Allowances::<T>::insert(supersig_id, &who, Allowance { remaining, ..allowance });
+
+			Self::deposit_event(Event::<T>::AllowanceSpent(supersig_account, who, dest, amount));
+
+			Ok(())
 		}
 
-		pub fn compute_proportional_amount_to_unreserve(
-			total_deposit: BalanceOf<T>,
-			initial_total_members: u32,
-			removed_members: usize,
-		) -> Result<BalanceOf<T>, Error<T>> {
-			let amount_to_unreserve = total_deposit
-				.checked_div(
-					&<BalanceOf<T>>::try_from(initial_total_members)
-						.map_err(|_| Error::<T>::Conversion)?,
+		/// Set the outflow limit of the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `set_outflow_limit` will cap the native currency that executed calls can move out of
+		/// the supersig account to `max_amount` per `window` blocks. The window slides: at any
+		/// block, the outflows of the last `window` blocks count towards the limit. The approval
+		/// that would execute a call exceeding the limit fails with `OutflowLimitExceeded`, and
+		/// the call stays queued. `None` removes the limit.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_outflow_limit())]
+		pub fn set_outflow_limit(
+			origin: OriginFor<T>,
+			limit: Option<OutflowLimit<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			match limit.clone() {
+				Some(limit) => {
+					ensure!(!limit.window.is_zero(), Error::<T>::InvalidPeriod);
+					OutflowLimits::<T>::insert(supersig_id, limit);
+				},
+				None => {
+					OutflowLimits::<T>::remove(supersig_id);
+					Outflows::<T>::remove(supersig_id);
+				},
+			}
+
+			Self::deposit_event(Event::<T>::OutflowLimitSet(supersig_account, limit));
+
+			Ok(())
+		}
+
+		/// Create a supersig whose account is known in advance.
+		///
+		/// `create_supersig_with_salt` works like `create_supersig`, but the supersig account is
+		/// derived from the pallet id, the creator and `salt` instead of the supersig id. The
+		/// account can be computed before the supersig exists, with `salted_supersig_account`,
+		/// and receive funds. A creator can't use the same salt for two live supersigs.
+		///
+		/// The dispatch origin for this call must be `Signed`.
+		///
+		/// # <weight>
+		///
+		/// Related functions:
+		/// - `DepositAssets::transfer` will be called once to deposit the greater of an
+		///   existencial amount and the deposit on supersig, and once to escrow the deposit
+		/// - `frame_system::inc_providers` will be called once to protect the supersig from
+		///   deletion
+		#[pallet::call_index(16)]
+		#[transactional]
+		#[pallet::weight(
+			T::WeightInfo::create_supersig_with_salt(members.len() as u32)
+				.saturating_add(Pallet::<T>::cycle_check_weight(members.len() as u32))
+		)]
+		pub fn create_supersig_with_salt(
+			origin: OriginFor<T>,
+			members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
+			salt: Salt,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let supersig_id = Self::nonce_supersig();
+			let supersig_account = Self::salted_supersig_account(&who, &salt);
+			ensure!(
+				!SaltedSupersigs::<T>::contains_key(&supersig_account),
+				Error::<T>::SaltAlreadyUsed
+			);
+			SaltedSupersigs::<T>::insert(&supersig_account, supersig_id);
+			SaltedAccounts::<T>::insert(supersig_id, &supersig_account);
+
+			Self::do_create_supersig(&who, supersig_id, supersig_account, members)
+		}
+
+		/// Set the guardians of the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `set_guardians` will replace the accounts that can `freeze` the supersig, on top of
+		/// its Masters. Guardians don't need to be members. An empty list leaves the Masters
+		/// alone able to freeze the supersig.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_guardians(guardians.len() as u32))]
+		pub fn set_guardians(
+			origin: OriginFor<T>,
+			guardians: BoundedVec<T::AccountId, T::MaxAccountsPerTransaction>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			Guardians::<T>::insert(supersig_id, &guardians);
+
+			Self::deposit_event(Event::<T>::GuardiansSet(supersig_account, guardians.into()));
+
+			Ok(())
+		}
+
+		/// Freeze the supersig.
+		///
+		/// `freeze` will immediately stop the activity of the supersig, for example when the key
+		/// of a member is compromised. While frozen, no call can be proposed or executed but
+		/// `unfreeze`, members can't leave or rotate their key, and allowances can't be spent.
+		///
+		/// The dispatch origin for this call must be `Signed`, by a Master of the supersig or
+		/// one of its guardians
+		///
+		/// # <weight>
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(origin: OriginFor<T>, supersig_account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			if Self::members(supersig_id, &who) != Role::Master &&
+				!Self::guardians(supersig_id).contains(&who)
+			{
+				return Err(Error::<T>::NotAllowed.into())
+			}
+			Self::ensure_not_frozen(supersig_id)?;
+
+			Frozen::<T>::insert(supersig_id, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::<T>::SupersigFrozen(supersig_account, who));
+
+			Ok(())
+		}
+
+		/// Unfreeze the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `unfreeze` is the only call that can be proposed and approved while the supersig is
+		/// frozen. It needs the usual threshold of votes.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::unfreeze())]
+		pub fn unfreeze(origin: OriginFor<T>) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			Frozen::<T>::take(supersig_id).ok_or(Error::<T>::NotFrozen)?;
+
+			Self::deposit_event(Event::<T>::SupersigUnfrozen(supersig_account));
+
+			Ok(())
+		}
+
+		/// Set the inheritance of the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `set_inheritance` will let `beneficiary` claim the supersig once no call has been
+		/// proposed or voted for during `inactivity_period` blocks. The claim can be executed
+		/// after `challenge_period` blocks, if no member cancelled it. `None` removes the
+		/// inheritance and any pending claim.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_inheritance())]
+		pub fn set_inheritance(
+			origin: OriginFor<T>,
+			inheritance: Option<Inheritance<T::AccountId, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			match inheritance.clone() {
+				Some(inheritance) => {
+					ensure!(
+						!inheritance.inactivity_period.is_zero(),
+						Error::<T>::InvalidPeriod
+					);
+					Inheritances::<T>::insert(supersig_id, inheritance);
+				},
+				None => Inheritances::<T>::remove(supersig_id),
+			}
+			InheritanceClaims::<T>::remove(supersig_id);
+
+			Self::deposit_event(Event::<T>::InheritanceSet(supersig_account, inheritance));
+
+			Ok(())
+		}
+
+		/// Claim an inactive supersig.
+		///
+		/// `claim_inheritance` starts the challenge period, during which any member can cancel
+		/// the claim with `cancel_inheritance_claim`. The members must not have proposed or
+		/// voted for a call during the inactivity period of the inheritance.
+		///
+		/// The dispatch origin for this call must be `Signed` by the beneficiary of the
+		/// inheritance
+		///
+		/// # <weight>
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::claim_inheritance())]
+		pub fn claim_inheritance(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			let inheritance = Self::beneficiary_inheritance(supersig_id, &who)?;
+
+			ensure!(
+				!InheritanceClaims::<T>::contains_key(supersig_id),
+				Error::<T>::ClaimPending
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= Self::last_activity(supersig_id)
+					.saturating_add(inheritance.inactivity_period),
+				Error::<T>::SupersigActive
+			);
+
+			InheritanceClaims::<T>::insert(supersig_id, now);
+
+			Self::deposit_event(Event::<T>::InheritanceClaimed(supersig_account, who));
+
+			Ok(())
+		}
+
+		/// Cancel the pending inheritance claim of the supersig.
+		///
+		/// The dispatch origin for this call must be `Signed`, and the origin must be a
+		/// supersig's member
+		///
+		/// # <weight>
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::cancel_inheritance_claim())]
+		pub fn cancel_inheritance_claim(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			ensure!(Self::is_member(supersig_id, &who), Error::<T>::NotMember);
+			InheritanceClaims::<T>::take(supersig_id).ok_or(Error::<T>::NoClaim)?;
+			Self::record_activity(supersig_id);
+
+			Self::deposit_event(Event::<T>::InheritanceClaimCancelled(supersig_account, who));
+
+			Ok(())
+		}
+
+		/// Remove an inactive supersig in favor of its beneficiary.
+		///
+		/// `execute_inheritance` works like `delete_supersig` with the beneficiary as target,
+		/// once the challenge period of the claim is over.
+		///
+		/// The dispatch origin for this call must be `Signed` by the beneficiary of the
+		/// inheritance
+		///
+		/// # <weight>
+		#[pallet::call_index(23)]
+		#[transactional]
+		#[pallet::weight(T::WeightInfo::execute_inheritance())]
+		pub fn execute_inheritance(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			let inheritance = Self::beneficiary_inheritance(supersig_id, &who)?;
+
+			let claimed_at = Self::inheritance_claims(supersig_id).ok_or(Error::<T>::NoClaim)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					claimed_at.saturating_add(inheritance.challenge_period),
+				Error::<T>::ChallengePeriodNotOver
+			);
+
+			Self::do_delete_supersig(supersig_id, supersig_account.clone(), &who)?;
+
+			Self::deposit_event(Event::<T>::InheritanceExecuted(supersig_account, who));
+
+			Ok(())
+		}
+		/// Submit a proposal that will be dispatched with the given origin once approved.
+		///
+		/// With `CallOrigin::Approved`, the call is dispatched with the pallet `Origin`, that
+		/// carries the supersig id, the weight that approved the call and the total weight, instead
+		/// of the supersig account. Other pallets can then require a share of the votes of a
+		/// supersig with `EnsureSupersigApprovalOf`.
+		///
+		/// Like the proposals of pallet-collective, `threshold` is the approving weight the call
+		/// needs to be executed. It can't be lower than a simple majority, nor higher than the total
+		/// voting weight. `None` executes the call with a simple majority.
+		///
+		/// The dispatch origin for this call must be `Signed`, and the origin must be a
+		/// supersig's member
+		#[pallet::call_index(24)]
+		#[pallet::weight(
+			T::WeightInfo::propose_call_with_origin(call.encode().len() as u32)
+				.saturating_add(Pallet::<T>::snapshot_weight(T::MaxSnapshotSize::get()))
+		)]
+		pub fn propose_call_with_origin(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+			call: Box<<T as pallet::Config>::Call>,
+			call_origin: CallOrigin,
+			threshold: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let call_len = call.encode().len() as u32;
+			let snapshot_size =
+				Self::do_propose_call(who, supersig_account, call, call_origin, threshold)?;
+			Ok(Some(
+				T::WeightInfo::propose_call_with_origin(call_len)
+					.saturating_add(Self::snapshot_weight(snapshot_size)),
+			)
+			.into())
+		}
+
+		/// Add a vault to the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `add_vault` will derive a new numbered sub-account from the supersig id, to keep funds
+		/// apart, e.g. operating funds, reserves and grant pools. Calls proposed with
+		/// `CallOrigin::Vault` are dispatched from the vault. Vaults are swept along with the
+		/// supersig account when the supersig is deleted.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::add_vault())]
+		pub fn add_vault(origin: OriginFor<T>) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			let vault = Self::vault_count(supersig_id);
+			ensure!(u32::from(vault) < T::MaxVaults::get(), Error::<T>::TooManyVaults);
+			VaultCount::<T>::insert(supersig_id, vault + 1);
+
+			Self::deposit_event(Event::<T>::VaultAdded(
+				supersig_account,
+				vault,
+				Self::vault_account(supersig_id, vault),
+			));
+
+			Ok(())
+		}
+
+		/// Send an XCM message from the supersig. You need to wrap this in a proposeCall function.
+		///
+		/// `send_xcm` will send `message` to `dest` through `XcmRouter`, prefixed with a
+		/// `DescendOrigin` to the `SupersigLocation` of the supersig, so the destination executes
+		/// it on behalf of the supersig, e.g. to move the assets it controls on a sibling chain.
+		/// The price of the delivery is charged to the supersig, at its `SupersigLocation`,
+		/// through `XcmExecutor`.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::send_xcm())]
+		pub fn send_xcm(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			message: Box<VersionedXcm<()>>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			let dest = MultiLocation::try_from(*dest).map_err(|()| Error::<T>::BadXcmVersion)?;
+			let mut message: Xcm<()> =
+				(*message).try_into().map_err(|()| Error::<T>::BadXcmVersion)?;
+			let interior = T::SupersigLocation::convert((supersig_id, supersig_account.clone()));
+			message.0.insert(0, xcm::latest::Instruction::DescendOrigin(interior));
+
+			let (ticket, price) = xcm::latest::validate_send::<T::XcmRouter>(dest, message)
+				.map_err(|_| Error::<T>::XcmSendFailed)?;
+			T::XcmExecutor::charge_fees(interior, price).map_err(|_| Error::<T>::FeesNotMet)?;
+			let message_id =
+				T::XcmRouter::deliver(ticket).map_err(|_| Error::<T>::XcmSendFailed)?;
+
+			Self::deposit_event(Event::<T>::XcmSent(supersig_account, dest, message_id));
+
+			Ok(())
+		}
+
+		/// Allow a location on another chain to vote for a member. You need to wrap this in a
+		/// proposeCall function.
+		///
+		/// `set_remote_voter` will let `location` approve calls with `approve_call_remote`, with
+		/// the vote weight of `member`. `None` removes the location. The location votes for the
+		/// account, it is removed when the account leaves the supersig or is rotated.
+		///
+		/// The dispatch origin for this call must be `Signed` by the supersig
+		///
+		/// # <weight>
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_remote_voter())]
+		pub fn set_remote_voter(
+			origin: OriginFor<T>,
+			location: Box<VersionedMultiLocation>,
+			member: Option<T::AccountId>,
+		) -> DispatchResult {
+			let supersig_account = ensure_signed(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			let location =
+				MultiLocation::try_from(*location).map_err(|()| Error::<T>::BadXcmVersion)?;
+
+			if let Some(member) = &member {
+				ensure!(Self::is_member(supersig_id, member), Error::<T>::NotMember);
+			}
+			if let Some(previous) = RemoteVoters::<T>::take(supersig_id, location) {
+				RemoteVoterLocations::<T>::remove((supersig_id, previous, location));
+			}
+			if let Some(member) = &member {
+				RemoteVoters::<T>::insert(supersig_id, location, member);
+				RemoteVoterLocations::<T>::insert((supersig_id, member, location), ());
+			}
+
+			Self::deposit_event(Event::<T>::RemoteVoterSet(supersig_account, location, member));
+
+			Ok(())
+		}
+
+		/// Approve a call from another chain.
+		///
+		/// `approve_call_remote` is sent as an XCM `Transact` by a location allowed with
+		/// `set_remote_voter`, and counts as an `approve_call` of the member it votes for.
+		///
+		/// The dispatch origin for this call must be `XcmOrigin`
+		///
+		/// # <weight>
+		#[pallet::call_index(28)]
+		#[transactional]
+		#[pallet::weight(
+			Pallet::<T>::approval_weight(
+				T::WeightInfo::approve_call_remote(),
+				Some(T::MaxCallWeight::get()),
+			)
+		)]
+		pub fn approve_call_remote(
+			origin: OriginFor<T>,
+			supersig_account: T::AccountId,
+			call_id: CallId,
+		) -> DispatchResultWithPostInfo {
+			let location = T::XcmOrigin::ensure_origin(origin)?;
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+			let who = Self::remote_voters(supersig_id, location)
+				.ok_or(Error::<T>::UnknownRemoteVoter)?;
+
+			let call_weight = Self::do_approve_call(supersig_id, supersig_account, call_id, who)?;
+			Ok(Some(Self::approval_weight(T::WeightInfo::approve_call_remote(), call_weight))
+				.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		pub fn get_supersig_id_from_account(
+			supersig_account: &T::AccountId,
+		) -> Result<SupersigId, pallet::Error<T>> {
+			let supersig_id = match Self::salted_supersigs(supersig_account) {
+				Some(supersig_id) => supersig_id,
+				None => {
+					let (account, supersig_id) = PalletId::try_from_sub_account(supersig_account)
+						.ok_or(Error::<T>::NotSupersig)?;
+					// Salted accounts also decode to an id, and salted supersigs don't answer
+					// to the account derived from their id
+					if account != T::PalletId::get() ||
+						Self::supersig_account(supersig_id) != *supersig_account
+					{
+						return Err(Error::<T>::NotSupersig)
+					}
+					supersig_id
+				},
+			};
+
+			// Collection backed supersigs may have no explicit member
+			let exists = Self::total_members(supersig_id) > 0 ||
+				matches!(Self::voting_mode(supersig_id), VotingMode::Collection(_));
+			if !exists {
+				return Err(Error::<T>::NotSupersig)
+			}
+			Ok(supersig_id)
+		}
+
+		// This function can fail after a storage mutation.
+		// extrinsics that use it should have the #[transactional] annotation.
+		fn do_delete_supersig(
+			supersig_id: SupersigId,
+			supersig_account: T::AccountId,
+			beneficiary: &T::AccountId,
+		) -> DispatchResult {
+			// Release all member related deposits
+			let total_deposit = TotalDeposit::<T>::take(supersig_id);
+			Self::refund_deposit(&supersig_account, total_deposit)?;
+
+			// Release all call related deposits
+			for preimage in Calls::<T>::iter_prefix_values(supersig_id) {
+				Self::release_proposal_deposit(&preimage)?;
+			}
+
+			// Empty the vaults balances, before their count is erased
+			// Will cause death of the accounts
+			// Will fail and revert the transaction if an account is not allowed to die
+			// due to an other pallet reference counter, held, reserved or frozen funds
+			for vault in 0..Self::vault_count(supersig_id) {
+				Self::sweep_account(&Self::vault_account(supersig_id, vault), beneficiary)?;
+			}
+
+			// Erase trace of this supersis in storage
+			Members::<T>::iter_key_prefix(supersig_id)
+				.for_each(|member| Self::unindex_member(supersig_id, &member));
+			Self::unchecked_remove_supersig_from_storages(supersig_id, &supersig_account);
+
+			// Empty the supersig account balance, same as the vaults, and drop the reference
+			// keeping it alive, which reaps it
+			Self::sweep_account(&supersig_account, beneficiary)?;
+			frame_system::Pallet::<T>::dec_providers(&supersig_account)?;
+
+			Self::deposit_event(Event::<T>::SupersigRemoved(supersig_account));
+
+			Ok(())
+		}
+
+		// This function can fail after a storage mutation.
+		// extrinsics that use it should have the #[transactional] annotation.
+		fn do_create_supersig(
+			who: &T::AccountId,
+			supersig_id: SupersigId,
+			supersig_account: T::AccountId,
+			members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
+		) -> DispatchResult {
+			// A supersig should at least have one member
+			if members.is_empty() {
+				return Err(Error::<T>::MustHaveAtLeastOneMember.into())
+			}
+
+			// Update Members and TotalMembers storages
+			let added_members = Self::internal_add_members(supersig_id, members)?;
+
+			// Bring account to existence
+			let deposit = Self::compute_deposit(size_of::<T::AccountId>() * added_members.len())?;
+			Self::open_supersig_account(who, supersig_id, &supersig_account, deposit)?;
+
+			NonceSupersig::<T>::put(supersig_id + 1);
+
+			Self::deposit_event(Event::<T>::SupersigCreated(supersig_account.clone()));
+			Self::deposit_event(Event::<T>::MembersAdded(supersig_account, added_members));
+
+			Ok(())
+		}
+
+		fn do_propose_call(
+			who: T::AccountId,
+			supersig_account: T::AccountId,
+			call: Box<<T as pallet::Config>::Call>,
+			call_origin: CallOrigin,
+			threshold: Option<u32>,
+		) -> Result<u32, DispatchError> {
+			let supersig_id = Self::get_supersig_id_from_account(&supersig_account)?;
+
+			if Self::frozen(supersig_id).is_some() {
+				ensure!(
+					matches!(call.is_sub_type(), Some(Call::unfreeze { .. })),
+					Error::<T>::SupersigFrozen
+				);
+			}
+
+			if let CallOrigin::Vault(vault) = call_origin {
+				ensure!(vault < Self::vault_count(supersig_id), Error::<T>::VaultNotFound);
+			}
+
+			let data = call.encode();
+
+			ensure!(
+				data.len() <= T::MaxCallDataSize::get() as usize,
+				Error::<T>::CallDataTooLarge
+			);
+			ensure!(
+				call.get_dispatch_info().weight.all_lte(T::MaxCallWeight::get()),
+				Error::<T>::CallTooHeavy
+			);
+
+			// Modify the propose_call extrinsic to check the number of active proposals before allowing a new one
+			let current_active_proposals = Self::active_proposals(supersig_id);
+    		ensure!(
+				current_active_proposals < T::MaxCallsPerAccount::get(), Error::<T>::TooManyActiveProposals);
+			
+			// Increment the number of active proposals for the Supersig account when a new proposal is submitted
+			ActiveProposals::<T>::mutate(supersig_id, |active_proposals| *active_proposals += 1);
+
+			// Incentive to remove proposal that won't be accepted
+			let deposit = Self::compute_deposit(data.len())?;
+			Self::escrow_deposit(&who, deposit)?;
+
+			// The encoded call is stored, along with the infos needed to unreserve the funds
+			// associated with it
+			let call_id = Self::nonce_call(supersig_id);
+			NonceCall::<T>::insert(supersig_id, call_id + 1);
+			let preimage = PreimageCall::<T::AccountId, DepositBalanceOf<T>> {
+				data,
+				provider: who.clone(),
+				deposit,
+			};
+			Calls::<T>::insert(supersig_id, call_id, preimage);
+			if call_origin != CallOrigin::Signed {
+				CallOrigins::<T>::insert(supersig_id, call_id, call_origin);
+			}
+			Self::record_activity(supersig_id);
+
+			let snapshot_size = match Self::voting_mode(supersig_id) {
+				VotingMode::Role => 0,
+				VotingMode::Token(asset) =>
+					Self::snapshot_vote_weights(supersig_id, call_id, asset)?,
+				VotingMode::Collection(collection) =>
+					Self::snapshot_item_count(supersig_id, call_id, &collection)?,
+			};
+
+			// Checked once the total weight of the call is known
+			if let Some(threshold) = threshold {
+				ensure!(
+					threshold <= Self::total_voting_weight(supersig_id, call_id),
+					Error::<T>::ThresholdTooHigh
+				);
+				CallThresholds::<T>::insert(supersig_id, call_id, threshold);
+			}
+
+			Self::deposit_event(Event::<T>::CallSubmitted(supersig_account, call_id, who));
+
+			Ok(snapshot_size)
+		}
+
+		// Cast the vote of `who` and execute the call if the threshold is reached. Returns the
+		// weight the call used when it is executed
+		fn do_approve_call(
+			supersig_id: SupersigId,
+			supersig_account: T::AccountId,
+			call_id: CallId,
+			who: T::AccountId,
+		) -> Result<Option<Weight>, DispatchError> {
+			let preimage = Self::calls(supersig_id, call_id).ok_or(Error::<T>::CallNotFound)?;
+			Self::ensure_can_execute(supersig_id, &preimage.data)?;
+
+			// Different roles, token balances or number of items held, have different voting weight
+			let vote_weight = match Self::voting_mode(supersig_id) {
+				// The items vote, not the accounts holding them
+				VotingMode::Collection(collection) =>
+					Self::record_item_votes(supersig_id, call_id, &collection, &who)?,
+				_ => {
+					if Self::members_votes((supersig_id, call_id, who.clone())) {
+						return Err(Error::<T>::AlreadyVoted.into())
+					}
+					Self::compute_vote_weight(supersig_id, call_id, &who)?
+				},
+			};
+
+			// Update storage with the user vote
+			MembersVotes::<T>::insert((supersig_id, call_id, who.clone()), true);
+			Votes::<T>::mutate(supersig_id, call_id, |val| {
+				*val = val.saturating_add(vote_weight)
+			});
+			Self::record_activity(supersig_id);
+			
+
+			Self::deposit_event(Event::<T>::CallVoted(
+				supersig_account.clone(),
+				call_id,
+				who,
+			));
+
+			let total_votes = Self::votes(supersig_id, call_id);
+			if total_votes >= Self::compute_threshold(supersig_id, call_id) {
+				if let Some(preimage) = Self::calls(supersig_id, call_id) {
+					let (origin, dispatch_account) =
+						Self::dispatch_origin(supersig_id, &supersig_account, call_id, total_votes);
+
+					// free storage and release deposit
+					Self::unchecked_remove_call_from_storages(supersig_id, call_id);
+					Self::release_proposal_deposit(&preimage)?;
+
+					// Decrement the number of active proposals when the proposal is approved or rejected, freeing up space for a new live proposal. 
+					ActiveProposals::<T>::mutate(supersig_id, |active_proposals| *active_proposals = active_proposals.saturating_sub(1));
+
+					// Try to decode and execute the call
+					let balance_before = T::Currency::total_balance(&dispatch_account);
+					let (res, call_weight) =
+						if let Ok(call) = <T as Config>::Call::decode(&mut &preimage.data[..]) {
+							let info = call.get_dispatch_info();
+							let res = call.dispatch(origin);
+							// A failed call is charged what it used too
+							let call_weight = extract_actual_weight(&res, &info);
+							(res, call_weight)
+						} else {
+							(Err(Error::<T>::BadEncodedCall.into()), Weight::zero())
+						};
+
+					// Reverts the whole approval, including the execution, if the cap is exceeded
+					let outflow = balance_before
+						.saturating_sub(T::Currency::total_balance(&dispatch_account));
+					Self::record_outflow(supersig_id, outflow)?;
+
+					Self::deposit_event(Event::<T>::CallExecutionAttempted(
+						supersig_account,
+						call_id,
+						res,
+					));
+
+					return Ok(Some(call_weight))
+				}
+			}
+
+			Ok(None)
+		}
+
+		// The origin an approved call is dispatched with, and the account its funds leave
+		pub(crate) fn dispatch_origin(
+			supersig_id: SupersigId,
+			supersig_account: &T::AccountId,
+			call_id: CallId,
+			total_votes: u32,
+		) -> (<T as Config>::RuntimeOrigin, T::AccountId) {
+			match Self::call_origins(supersig_id, call_id) {
+				CallOrigin::Signed => (
+					frame_system::RawOrigin::Signed(supersig_account.clone()).into(),
+					supersig_account.clone(),
+				),
+				CallOrigin::Approved => (
+					Origin::Approved(
+						supersig_id,
+						total_votes,
+						Self::total_voting_weight(supersig_id, call_id),
+					)
+					.into(),
+					supersig_account.clone(),
+				),
+				CallOrigin::Vault(vault) => {
+					let vault_account = Self::vault_account(supersig_id, vault);
+					(frame_system::RawOrigin::Signed(vault_account.clone()).into(), vault_account)
+				},
+			}
+		}
+
+		pub(crate) fn unchecked_remove_call_from_storages(
+			supersig_id: SupersigId,
+			call_id: CallId,
+		) {
+			Calls::<T>::remove(supersig_id, call_id);
+			Votes::<T>::remove(supersig_id, call_id);
+			let _ = MembersVotes::<T>::clear_prefix((supersig_id, call_id), u32::MAX, None);
+			CallOrigins::<T>::remove(supersig_id, call_id);
+			CallThresholds::<T>::remove(supersig_id, call_id);
+			TotalWeightSnapshots::<T>::remove(supersig_id, call_id);
+			let _ = VoteWeightSnapshots::<T>::clear_prefix((supersig_id, call_id), u32::MAX, None);
+			ItemSnapshots::<T>::remove(supersig_id, call_id);
+			let _ = ItemVotes::<T>::clear_prefix((supersig_id, call_id), u32::MAX, None);
+			let _ = ChildVotes::<T>::clear_prefix((supersig_id, call_id), u32::MAX, None);
+			ChildMembersVotes::<T>::drain_prefix((supersig_id, call_id))
+				.for_each(|((child_id, member), _)| Self::uncount_child_vote(child_id, &member));
+		}
+
+		fn unchecked_remove_supersig_from_storages(
+			supersig_id: SupersigId,
+			supersig_account: &T::AccountId,
+		) {
+			NonceCall::<T>::remove(supersig_id);
+			let _ = Members::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			TotalMembers::<T>::remove(supersig_id);
+			TotalMasters::<T>::remove(supersig_id);
+			SupersigInvariants::<T>::remove(supersig_id);
+			VotingModes::<T>::remove(supersig_id);
+			let _ = Allowances::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			OutflowLimits::<T>::remove(supersig_id);
+			Outflows::<T>::remove(supersig_id);
+			let _ = Calls::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = Votes::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = MembersVotes::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+			let _ = CallOrigins::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = CallThresholds::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = TotalWeightSnapshots::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = VoteWeightSnapshots::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+			let _ = ItemSnapshots::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = ItemVotes::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+			let _ = ChildVotes::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+			ChildMembersVotes::<T>::drain_prefix((supersig_id,))
+				.for_each(|((_, child_id, member), _)| Self::uncount_child_vote(child_id, &member));
+			let _ = PendingChildVotes::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			SaltedSupersigs::<T>::remove(supersig_account);
+			SaltedAccounts::<T>::remove(supersig_id);
+			Frozen::<T>::remove(supersig_id);
+			Guardians::<T>::remove(supersig_id);
+			Inheritances::<T>::remove(supersig_id);
+			LastActivity::<T>::remove(supersig_id);
+			InheritanceClaims::<T>::remove(supersig_id);
+			VaultCount::<T>::remove(supersig_id);
+			let _ = RemoteVoters::<T>::clear_prefix(supersig_id, u32::MAX, None);
+			let _ = RemoteVoterLocations::<T>::clear_prefix((supersig_id,), u32::MAX, None);
+		}
+
+		fn internal_add_members(
+			supersig_id: SupersigId,
+			members: BoundedVec<(T::AccountId, Role), T::MaxAccountsPerTransaction>,
+		) -> Result<Vec<(T::AccountId, Role)>, Error<T>> {
+			let mut added = Vec::new();
+
+			for (member, role) in members {
+				let old_role = Self::members(supersig_id, &member);
+				if old_role == Role::NotMember {
+					Self::ensure_no_cycle(supersig_id, &member)?;
+					Self::index_member(supersig_id, &member);
+					added.push((member.clone(), role.clone()));
+				}
+				Self::record_role_change(supersig_id, &old_role, &role);
+				Members::<T>::insert(supersig_id, member, role);
+			}
+
+			TotalMembers::<T>::try_mutate(supersig_id, |n| {
+				*n = n
+					.checked_add(added.len().try_into().map_err(|_| Error::<T>::Conversion)?)
+					.ok_or(Error::<T>::Overflow)?;
+
+				Ok(())
+			})?;
+
+			// Existing members may have been given a new role
+			Self::ensure_invariants(supersig_id)?;
+
+			Ok(added)
+		}
+
+		fn internal_remove_members(
+			supersig_id: SupersigId,
+			members: BoundedVec<T::AccountId, T::MaxAccountsPerTransaction>,
+		) -> Result<Vec<T::AccountId>, pallet::Error<T>> {
+			let mut removed = Vec::new();
+
+			for member in members {
+				let role = Self::members(supersig_id, &member);
+				if role != Role::NotMember {
+					Self::record_role_change(supersig_id, &role, &Role::NotMember);
+					Members::<T>::remove(supersig_id, member.clone());
+					Self::unindex_member(supersig_id, &member);
+					Allowances::<T>::remove(supersig_id, &member);
+					Self::remove_remote_voters(supersig_id, &member);
+					removed.push(member);
+				}
+			}
+
+			TotalMembers::<T>::try_mutate(supersig_id, |n| {
+				let new_total_members =
+					n.saturating_sub(removed.len().try_into().map_err(|_| Error::<T>::Conversion)?);
+				if new_total_members < 1 {
+					return Err(Error::<T>::MustHaveAtLeastOneMember)
+				}
+
+				*n = new_total_members;
+
+				Ok(())
+			})?;
+
+			Self::ensure_invariants(supersig_id)?;
+
+			Ok(removed)
+		}
+
+		// This function can fail after a storage mutation.
+		// extrinsics that use it should have the #[transactional] annotation.
+		fn open_supersig_account(
+			creator: &T::AccountId,
+			supersig_id: SupersigId,
+			supersig_account: &T::AccountId,
+			deposit: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			// Prevent the supersig account to sign transaction that would kill it. The account
+			// must also stay alive when the whole transfer is escrowed
+			frame_system::Pallet::<T>::inc_providers(supersig_account);
+
+			let asset = T::DepositAssetId::get();
+			let amount_to_transfer = max(T::DepositAssets::minimum_balance(asset.clone()), deposit)
+				.checked_add(&Self::escrow_shortfall())
+				.ok_or(Error::<T>::Overflow)?;
+			T::DepositAssets::transfer(
+				asset,
+				creator,
+				supersig_account,
+				amount_to_transfer,
+				Preservation::Expendable,
+			)?;
+
+			// Incentive to delete supersigs that are no longer used
+			Self::escrow_and_record_deposit(supersig_id, supersig_account, deposit)
+		}
+
+		fn index_member(supersig_id: SupersigId, member: &T::AccountId) {
+			AccountSupersigs::<T>::insert(member, supersig_id, ());
+		}
+
+		// The locations that voted for a member that left can't vote anymore
+		fn remove_remote_voters(supersig_id: SupersigId, member: &T::AccountId) {
+			let locations: Vec<MultiLocation> =
+				RemoteVoterLocations::<T>::iter_key_prefix((supersig_id, member)).collect();
+			for location in locations {
+				RemoteVoters::<T>::remove(supersig_id, location);
+			}
+			let _ =
+				RemoteVoterLocations::<T>::clear_prefix((supersig_id, member), u32::MAX, None);
+		}
+
+		fn unindex_member(supersig_id: SupersigId, member: &T::AccountId) {
+			AccountSupersigs::<T>::remove(member, supersig_id);
+		}
+
+		// A vote of a child member for a call of a parent is no longer pending. The count is gone
+		// when the child supersig was deleted
+		fn uncount_child_vote(child_id: SupersigId, member: &T::AccountId) {
+			PendingChildVotes::<T>::mutate_exists(child_id, member, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0)
+			});
+		}
+
+		/// The allowance of a member, with a new period started if the last one is over
+		pub fn current_allowance(
+			supersig_id: SupersigId,
+			member: &T::AccountId,
+		) -> Option<Allowance<BalanceOf<T>, BlockNumberFor<T>>> {
+			let allowance = Self::allowances(supersig_id, member)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if now >= allowance.period_start.saturating_add(allowance.period) {
+				Some(Allowance {
+					remaining: allowance.amount,
+					period_start: now,
+					..allowance
+				})
+			} else {
+				Some(allowance)
+			}
+		}
+
+		// This function can fail after a storage mutation.
+		// extrinsics that use it should have the #[transactional] annotation.
+		pub(crate) fn record_outflow(
+			supersig_id: SupersigId,
+			amount: BalanceOf<T>,
+		) -> Result<(), Error<T>> {
+			let limit = match Self::outflow_limits(supersig_id) {
+				Some(limit) => limit,
+				None => return Ok(()),
+			};
+			if amount.is_zero() {
+				return Ok(())
+			}
+
+			Outflows::<T>::try_mutate(supersig_id, |buckets| {
+				let now = frame_system::Pallet::<T>::block_number();
+				// The outflows of the blocks that left the window no longer count
+				buckets.retain(|(block, _)| now < block.saturating_add(limit.window));
+				let spent = buckets
+					.iter()
+					.try_fold(amount, |acc, (_, bucket)| acc.checked_add(bucket))
+					.ok_or(Error::<T>::Overflow)?;
+				ensure!(spent <= limit.max_amount, Error::<T>::OutflowLimitExceeded);
+
+				match buckets.iter_mut().last() {
+					Some((block, bucket)) if *block == now =>
+						*bucket = bucket.saturating_add(amount),
+					_ => {
+						let mut new_bucket = (now, amount);
+						// Merging the oldest bucket into the next one keeps its outflow in the
+						// window for longer, never shorter
+						if buckets.is_full() {
+							let (_, oldest) = buckets.remove(0);
+							match buckets.iter_mut().next() {
+								Some((_, next)) => *next = next.saturating_add(oldest),
+								None => new_bucket.1 = new_bucket.1.saturating_add(oldest),
+							}
+						}
+						buckets.try_push(new_bucket).map_err(|_| Error::<T>::Overflow)?;
+					},
+				}
+				Ok(())
+			})
+		}
+
+		/// The native currency that left the supersig account during the outflow window ending
+		/// at the current block
+		pub fn current_outflow(supersig_id: SupersigId) -> BalanceOf<T> {
+			let window = match Self::outflow_limits(supersig_id) {
+				Some(limit) => limit.window,
+				None => return Zero::zero(),
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::outflows(supersig_id)
+				.into_iter()
+				.filter(|(block, _)| now < block.saturating_add(window))
+				.fold(Zero::zero(), |acc: BalanceOf<T>, (_, spent)| acc.saturating_add(spent))
+		}
+
+		pub(crate) fn compute_deposit(data_size: usize) -> Result<DepositBalanceOf<T>, Error<T>> {
+			let bytes_stored: u32 = data_size.try_into().map_err(|_| Error::<T>::Conversion)?;
+
+			Ok(<DepositBalanceOf<T>>::from(bytes_stored).saturating_mul(T::DepositPerByte::get()))
+		}
+
+		/// The account the deposits are escrowed in
+		pub fn deposit_account() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(T::Hashing::hash_of(b"deposits"))
+		}
+
+		// Transfer `amount` of the deposit asset from `who` to the escrow. The escrow is kept
+		// alive: when it is empty, `who` also pays its minimum balance, which is never refunded
+		pub(crate) fn escrow_deposit(
+			who: &T::AccountId,
+			amount: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(())
+			}
+			T::DepositAssets::transfer(
+				T::DepositAssetId::get(),
+				who,
+				&Self::deposit_account(),
+				amount.checked_add(&Self::escrow_shortfall()).ok_or(Error::<T>::Overflow)?,
+				Preservation::Expendable,
+			)?;
+			Ok(())
+		}
+
+		// What the escrow lacks to reach the minimum balance of the deposit asset
+		pub(crate) fn escrow_shortfall() -> DepositBalanceOf<T> {
+			let asset = T::DepositAssetId::get();
+			T::DepositAssets::minimum_balance(asset.clone())
+				.saturating_sub(T::DepositAssets::balance(asset, &Self::deposit_account()))
+		}
+
+		// Transfer `amount` of the deposit asset back from the escrow to `who`, or what can be
+		// taken from the escrow without reaping it when it is less
+		fn refund_deposit(who: &T::AccountId, amount: DepositBalanceOf<T>) -> DispatchResult {
+			let asset = T::DepositAssetId::get();
+			let escrow = Self::deposit_account();
+			let amount = amount.min(T::DepositAssets::reducible_balance(
+				asset.clone(),
+				&escrow,
+				Preservation::Preserve,
+				Fortitude::Polite,
+			));
+			if amount.is_zero() {
+				return Ok(())
+			}
+			T::DepositAssets::transfer(asset, &escrow, who, amount, Preservation::Preserve)?;
+			Ok(())
+		}
+
+		// This function can fail after a storage mutation.
+		// extrinsics that use it should have the #[transactional] annotation.
+		fn escrow_and_record_deposit(
+			supersig_id: SupersigId,
+			supersig_account: &T::AccountId,
+			deposit: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			Self::escrow_deposit(supersig_account, deposit)?;
+			TotalDeposit::<T>::try_mutate(supersig_id, |val| {
+				*val = val.checked_add(&deposit).ok_or(Error::<T>::Overflow)?;
+				Ok(())
+			})
+		}
+
+		fn refund_and_record_deposit(
+			supersig_id: SupersigId,
+			supersig_account: &T::AccountId,
+			amount: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			Self::refund_deposit(supersig_account, amount)?;
+			TotalDeposit::<T>::mutate(supersig_id, |val| *val = val.saturating_sub(amount));
+			Ok(())
+		}
+
+		pub(crate) fn release_proposal_deposit(
+			preimage: &PreimageCall<T::AccountId, DepositBalanceOf<T>>,
+		) -> DispatchResult {
+			Self::refund_deposit(&preimage.provider, preimage.deposit)
+		}
+
+		fn compute_vote_weight(
+			supersig_id: SupersigId,
+			call_id: CallId,
+			who: &T::AccountId,
+		) -> Result<u32, Error<T>> {
+			let role = Self::members(supersig_id, who);
+			match Self::voting_mode(supersig_id) {
+				VotingMode::Role => Self::weight_of_role(&role, Self::total_members(supersig_id))
+					.ok_or(Error::<T>::NotMember),
+				VotingMode::Token(_) => {
+					if role == Role::NotMember {
+						return Err(Error::<T>::NotMember)
+					}
+					Ok(Self::vote_weight_snapshots((supersig_id, call_id, who.clone())))
+				},
+				VotingMode::Collection(collection) => {
+					let items = Self::unvoted_items(supersig_id, call_id, &collection, who)?;
+					items.len().try_into().map_err(|_| Error::<T>::Conversion)
+				},
+			}
+		}
+
+		fn record_activity(supersig_id: SupersigId) {
+			LastActivity::<T>::insert(supersig_id, frame_system::Pallet::<T>::block_number());
+		}
+
+		fn beneficiary_inheritance(
+			supersig_id: SupersigId,
+			who: &T::AccountId,
+		) -> Result<Inheritance<T::AccountId, BlockNumberFor<T>>, Error<T>> {
+			Self::inheritances(supersig_id)
+				.filter(|inheritance| inheritance.beneficiary == *who)
+				.ok_or(Error::<T>::NoInheritance)
+		}
+
+		fn ensure_not_frozen(supersig_id: SupersigId) -> Result<(), Error<T>> {
+			ensure!(Self::frozen(supersig_id).is_none(), Error::<T>::SupersigFrozen);
+			Ok(())
+		}
+
+		// While the supersig is frozen, only a call to `unfreeze` can be voted for and executed
+		pub(crate) fn ensure_can_execute(
+			supersig_id: SupersigId,
+			data: &[u8],
+		) -> Result<(), Error<T>> {
+			if Self::frozen(supersig_id).is_none() {
+				return Ok(())
+			}
+			let is_unfreeze = <T as Config>::Call::decode(&mut &data[..])
+				.map_or(false, |call| matches!(call.is_sub_type(), Some(Call::unfreeze { .. })));
+			ensure!(is_unfreeze, Error::<T>::SupersigFrozen);
+			Ok(())
+		}
+
+		pub fn supersig_account(supersig_id: SupersigId) -> T::AccountId {
+			Self::salted_accounts(supersig_id)
+				.unwrap_or_else(|| T::PalletId::get().into_sub_account_truncating(supersig_id))
+		}
+
+		/// The account of the supersig `creator` would create with `salt`
+		pub fn salted_supersig_account(creator: &T::AccountId, salt: &Salt) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(T::Hashing::hash_of(&(creator, salt)))
+		}
+
+		/// The account of the vault `vault` of the supersig `supersig_id`
+		pub fn vault_account(supersig_id: SupersigId, vault: VaultIndex) -> T::AccountId {
+			T::PalletId::get()
+				.into_sub_account_truncating(T::Hashing::hash_of(&(b"vault", supersig_id, vault)))
+		}
+
+		// Transfer the whole deposit asset and `Currency` balances of `account`
+		fn sweep_account(account: &T::AccountId, beneficiary: &T::AccountId) -> DispatchResult {
+			let asset = T::DepositAssetId::get();
+			let deposit_balance = T::DepositAssets::total_balance(asset.clone(), account);
+			if !deposit_balance.is_zero() {
+				T::DepositAssets::transfer(
+					asset,
+					account,
+					beneficiary,
+					deposit_balance,
+					Preservation::Expendable,
+				)
+				.map_err(|_| Error::<T>::SupersigHaveLockedFunds)?;
+			}
+			let balance = T::Currency::total_balance(account);
+			if !balance.is_zero() {
+				T::Currency::transfer(account, beneficiary, balance, Preservation::Expendable)
+					.map_err(|_| Error::<T>::SupersigHaveLockedFunds)?;
+			}
+			Ok(())
+		}
+
+		// Adding `member` to `supersig_id` must not make `supersig_id` one of its own members
+		fn ensure_no_cycle(supersig_id: SupersigId, member: &T::AccountId) -> Result<(), Error<T>> {
+			let mut visits = T::MaxNestingVisits::get();
+			if *member == Self::supersig_account(supersig_id) ||
+				Self::has_descendant(member, supersig_id, T::MaxNestingDepth::get(), &mut visits)?
+			{
+				return Err(Error::<T>::MembershipCycle)
+			}
+			Ok(())
+		}
+
+		// The weight of looking for membership cycles when adding `members` members. Visiting a
+		// member, and telling whether it is a supersig, takes at most 5 reads
+		pub(crate) fn cycle_check_weight(members: u32) -> Weight {
+			let visits = u64::from(T::MaxNestingVisits::get()).saturating_mul(members.into());
+			T::DbWeight::get().reads(visits.saturating_mul(5))
+		}
+
+		// Whether `target` is a member of the `account` supersig, or of one of its descendants.
+		// At most `visits` members are visited
+		fn has_descendant(
+			account: &T::AccountId,
+			target: SupersigId,
+			depth: u32,
+			visits: &mut u32,
+		) -> Result<bool, Error<T>> {
+			let supersig_id = match Self::get_supersig_id_from_account(account) {
+				Ok(supersig_id) => supersig_id,
+				Err(_) => return Ok(false),
+			};
+			if supersig_id == target {
+				return Ok(true)
+			}
+			if depth == 0 {
+				return Err(Error::<T>::NestingTooDeep)
+			}
+			for member in Members::<T>::iter_key_prefix(supersig_id) {
+				*visits = visits.checked_sub(1).ok_or(Error::<T>::NestingTooDeep)?;
+				if Self::has_descendant(&member, target, depth - 1, visits)? {
+					return Ok(true)
+				}
+			}
+			Ok(false)
+		}
+
+		pub fn is_member(supersig_id: SupersigId, who: &T::AccountId) -> bool {
+			match Self::voting_mode(supersig_id) {
+				VotingMode::Collection(collection) =>
+					T::Nfts::owned_in_collection(&collection, who).next().is_some(),
+				_ => Self::members(supersig_id, who) != Role::NotMember,
+			}
+		}
+
+		/// The distinct holders of the items of a collection, ordered by account
+		pub fn collection_holders(collection: &CollectionIdOf<T>) -> BTreeSet<T::AccountId> {
+			T::Nfts::items(collection)
+				.filter_map(|item| T::Nfts::owner(collection, &item))
+				.collect()
+		}
+
+		// The items snapshotted for the call that `who` holds and that didn't vote yet. At most
+		// `MaxSnapshotSize` items are read
+		pub(crate) fn unvoted_items(
+			supersig_id: SupersigId,
+			call_id: CallId,
+			collection: &CollectionIdOf<T>,
+			who: &T::AccountId,
+		) -> Result<Vec<ItemIdOf<T>>, Error<T>> {
+			let owned: Vec<ItemIdOf<T>> = Self::item_snapshots(supersig_id, call_id)
+				.into_iter()
+				.filter(|item| T::Nfts::owner(collection, item).as_ref() == Some(who))
+				.collect();
+			if owned.is_empty() {
+				return Err(Error::<T>::NotMember)
+			}
+			let unvoted: Vec<ItemIdOf<T>> = owned
+				.into_iter()
+				.filter(|item| !Self::item_votes((supersig_id, call_id, item.clone())))
+				.collect();
+			if unvoted.is_empty() {
+				return Err(Error::<T>::AlreadyVoted)
+			}
+			Ok(unvoted)
+		}
+
+		fn record_item_votes(
+			supersig_id: SupersigId,
+			call_id: CallId,
+			collection: &CollectionIdOf<T>,
+			who: &T::AccountId,
+		) -> Result<u32, Error<T>> {
+			let items = Self::unvoted_items(supersig_id, call_id, collection, who)?;
+			for item in items.iter() {
+				ItemVotes::<T>::insert((supersig_id, call_id, item.clone()), true);
+			}
+			items.len().try_into().map_err(|_| Error::<T>::Conversion)
+		}
+
+		// The weight the votes for a call are measured against, a simple majority of it executes
+		// the call
+		pub(crate) fn compute_total_weight(supersig_id: SupersigId, call_id: CallId) -> u32 {
+			match Self::voting_mode(supersig_id) {
+				VotingMode::Role => Self::total_members(supersig_id),
+				VotingMode::Token(_) | VotingMode::Collection(_) =>
+					Self::total_weight_snapshots(supersig_id, call_id),
+			}
+		}
+
+		// The sum of the voting weights of all the members, which is more than the number of
+		// members in `Role` mode, since a master weights more than one
+		pub(crate) fn total_voting_weight(supersig_id: SupersigId, call_id: CallId) -> u32 {
+			match Self::voting_mode(supersig_id) {
+				VotingMode::Role => Self::total_role_weight(supersig_id),
+				_ => Self::compute_total_weight(supersig_id, call_id),
+			}
+		}
+
+		// The approving weight that executes a call: a simple majority, or the threshold set by
+		// the proposer when it is higher
+		pub(crate) fn compute_threshold(supersig_id: SupersigId, call_id: CallId) -> u32 {
+			let majority = Self::simple_majority(Self::compute_total_weight(supersig_id, call_id));
+			max(majority, Self::call_thresholds(supersig_id, call_id).unwrap_or_default())
+		}
+
+		// The smallest weight that is more than half of `total_weight`
+		pub(crate) fn simple_majority(total_weight: u32) -> u32 {
+			total_weight / 2 + 1
+		}
+
+		// Each member weights its part, in billionths, of the asset held by all the members
+		fn snapshot_vote_weights(
+			supersig_id: SupersigId,
+			call_id: CallId,
+			asset: AssetIdOf<T>,
+		) -> Result<u32, Error<T>> {
+			let total_members = Self::total_members(supersig_id);
+			ensure!(total_members <= T::MaxSnapshotSize::get(), Error::<T>::SnapshotTooLarge);
+
+			let balances: Vec<(T::AccountId, AssetBalanceOf<T>)> =
+				Members::<T>::iter_key_prefix(supersig_id)
+					.map(|member| {
+						let balance = <T::Assets as fungibles::Inspect<T::AccountId>>::balance(
+							asset.clone(),
+							&member,
+						);
+						(member, balance)
+					})
+					.collect();
+			let total_balance = balances
+				.iter()
+				.fold(AssetBalanceOf::<T>::zero(), |acc, (_, balance)| acc.saturating_add(*balance));
+			ensure!(!total_balance.is_zero(), Error::<T>::NoAssetHolder);
+
+			let mut total_weight = 0u32;
+			for (member, balance) in balances {
+				let weight = Perbill::from_rational(balance, total_balance).deconstruct();
+				if weight > 0 {
+					VoteWeightSnapshots::<T>::insert((supersig_id, call_id, member), weight);
+					total_weight = total_weight.saturating_add(weight);
+				}
+			}
+			TotalWeightSnapshots::<T>::insert(supersig_id, call_id, total_weight);
+			Ok(total_members)
+		}
+
+		// Each item of the collection weights one vote. The items are snapshotted when the call
+		// is proposed, so that minting or burning items doesn't move the threshold of live calls,
+		// and items minted later can't vote
+		fn snapshot_item_count(
+			supersig_id: SupersigId,
+			call_id: CallId,
+			collection: &CollectionIdOf<T>,
+		) -> Result<u32, Error<T>> {
+			let max_items = T::MaxSnapshotSize::get();
+			let items: BoundedVec<ItemIdOf<T>, T::MaxSnapshotSize> =
+				T::Nfts::items(collection)
+					.take(max_items as usize + 1)
+					.collect::<Vec<_>>()
+					.try_into()
+					.map_err(|_| Error::<T>::SnapshotTooLarge)?;
+			let count = items.len() as u32;
+			TotalWeightSnapshots::<T>::insert(supersig_id, call_id, count);
+			ItemSnapshots::<T>::insert(supersig_id, call_id, items);
+			Ok(count)
+		}
+
+		// The weight of snapshotting the vote weights of a call, `size` being the number of members
+		// or items snapshotted. A member costs the read of its asset balance and the write of its
+		// weight
+		pub(crate) fn snapshot_weight(size: u32) -> Weight {
+			let size = u64::from(size);
+			T::DbWeight::get().reads_writes(size.saturating_mul(2), size.saturating_add(1))
+		}
+
+		// The weight of recording the votes of the items a voter holds, `size` being the number
+		// of items snapshotted. The snapshot is read, and each item costs the read of its owner,
+		// the read and write of its vote, and the removal of its vote once the call is gone
+		pub(crate) fn item_votes_weight(size: u32) -> Weight {
+			let size = u64::from(size);
+			T::DbWeight::get()
+				.reads_writes(size.saturating_mul(2).saturating_add(1), size.saturating_mul(2))
+		}
+
+		// The weight of an approval, `vote` being the weight of casting it, and `call_weight` the
+		// weight the call used when the approval executed it
+		pub(crate) fn approval_weight(vote: Weight, call_weight: Option<Weight>) -> Weight {
+			let vote = vote
+				.saturating_add(Self::item_votes_weight(T::MaxSnapshotSize::get()))
+				// the removal of the vote once the call is executed or removed
+				.saturating_add(T::DbWeight::get().writes(1));
+			match call_weight {
+				Some(call_weight) =>
+					vote.saturating_add(Self::execution_weight()).saturating_add(call_weight),
+				None => vote,
+			}
+		}
+
+		// The weight of executing an approved call, the call itself aside. It reads the call
+		// again, its origin, threshold and total weight snapshot, TotalMembers and TotalMasters
+		// for an `Approved` origin, the escrow and provider accounts, ActiveProposals, the balance
+		// of the dispatch account twice, and OutflowLimits and Outflows for the outflow check.
+		// It writes the six call storages removed, the five prefixes cleared, the two accounts
+		// of the deposit refund, ActiveProposals and Outflows. Clearing a prefix costs a write
+		// per entry, each paid by the vote that wrote it
+		pub(crate) fn execution_weight() -> Weight {
+			T::DbWeight::get().reads_writes(13, 15)
+		}
+
+		// The sum of the role based vote weights of all the members
+		pub(crate) fn total_role_weight(supersig_id: SupersigId) -> u32 {
+			let total_members = Self::total_members(supersig_id);
+			let masters = Self::total_masters(supersig_id);
+			let master_weight = Self::weight_of_role(&Role::Master, total_members).unwrap_or(0);
+			total_members
+				.saturating_sub(masters)
+				.saturating_add(masters.saturating_mul(master_weight))
+		}
+
+		// Keep `TotalMasters` up to date when the role of a member changes, `NotMember` for the
+		// members that leave
+		fn record_role_change(supersig_id: SupersigId, old_role: &Role, new_role: &Role) {
+			TotalMasters::<T>::mutate(supersig_id, |masters| {
+				match (old_role == &Role::Master, new_role == &Role::Master) {
+					(false, true) => *masters = masters.saturating_add(1),
+					(true, false) => *masters = masters.saturating_sub(1),
+					_ => (),
+				}
+			});
+		}
+
+		pub(crate) fn weight_of_role(role: &Role, total_members: u32) -> Option<u32> {
+			match role {
+				Role::Standard => Some(1),
+				Role::Master => Some(max(total_members / 2, 1)),
+				Role::NotMember => None,
+			}
+		}
+
+		// Must be called after the membership storages have been updated
+		fn ensure_invariants(supersig_id: SupersigId) -> Result<(), Error<T>> {
+			let invariants = Self::invariants(supersig_id);
+			if invariants == Invariants::default() {
+				return Ok(())
+			}
+
+			let masters = Self::total_masters(supersig_id);
+			let total_weight = Self::total_role_weight(supersig_id);
+
+			if masters < invariants.min_masters || total_weight < invariants.min_total_weight {
+				return Err(Error::<T>::InvariantsViolated)
+			}
+
+			Ok(())
+		}
+
+		pub fn compute_proportional_amount_to_unreserve(
+			total_deposit: DepositBalanceOf<T>,
+			initial_total_members: u32,
+			removed_members: usize,
+		) -> Result<DepositBalanceOf<T>, Error<T>> {
+			let amount_to_unreserve = total_deposit
+				.checked_div(
+					&<DepositBalanceOf<T>>::try_from(initial_total_members)
+						.map_err(|_| Error::<T>::Conversion)?,
 				)
 				.ok_or(Error::<T>::Overflow)?
 				.checked_mul(
-					&<BalanceOf<T>>::try_from(removed_members)
+					&<DepositBalanceOf<T>>::try_from(removed_members)
 						.map_err(|_| Error::<T>::Conversion)?,
 				)
 				.ok_or(Error::<T>::Overflow)?;
@@ -845,3 +2968,169 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Whether `approvals` is at least `N / D` of `total`
+fn is_approved_by<const N: u32, const D: u32>(approvals: u32, total: u32) -> bool {
+	u64::from(approvals).saturating_mul(D.into()) >= u64::from(total).saturating_mul(N.into())
+}
+
+/// Ensures the origin is a call approved by at least `N / D` of the total weight of any supersig.
+/// The success value is the id of the supersig.
+///
+/// Anyone can create a supersig they are the only member of, so this origin alone authorizes
+/// nothing: the caller must check the returned id. Use `EnsureSupersigApprovalOf` to require the
+/// approval of a given supersig.
+pub struct EnsureSupersigApproval<const N: u32, const D: u32>;
+impl<O: Into<Result<Origin, O>> + From<Origin>, const N: u32, const D: u32> EnsureOrigin<O>
+	for EnsureSupersigApproval<N, D>
+{
+	type Success = SupersigId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			Origin::Approved(supersig_id, approvals, total)
+				if is_approved_by::<N, D>(approvals, total) =>
+				Ok(supersig_id),
+			o => Err(O::from(o)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(Origin::Approved(0, 1, 1)))
+	}
+}
+
+/// Ensures the origin is a call approved by at least `N / D` of the total weight of the supersig
+/// `Id`, e.g. "approved by 2/3 of the treasury supersig".
+pub struct EnsureSupersigApprovalOf<Id, const N: u32, const D: u32>(PhantomData<Id>);
+impl<
+		O: Into<Result<Origin, O>> + From<Origin>,
+		Id: Get<SupersigId>,
+		const N: u32,
+		const D: u32,
+	> EnsureOrigin<O> for EnsureSupersigApprovalOf<Id, N, D>
+{
+	type Success = SupersigId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			Origin::Approved(supersig_id, approvals, total)
+				if supersig_id == Id::get() && is_approved_by::<N, D>(approvals, total) =>
+				Ok(supersig_id),
+			o => Err(O::from(o)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(Origin::Approved(Id::get(), 1, 1)))
+	}
+}
+
+/// Represents a supersig on other chains by its account, e.g. `../Parachain(1000)/AccountId32`
+pub struct SupersigAsAccountId32<Network>(PhantomData<Network>);
+impl<AccountId: Into<[u8; 32]>, Network: Get<Option<NetworkId>>>
+	Convert<(SupersigId, AccountId), InteriorMultiLocation> for SupersigAsAccountId32<Network>
+{
+	fn convert((_, supersig_account): (SupersigId, AccountId)) -> InteriorMultiLocation {
+		Junctions::X1(Junction::AccountId32 {
+			network: Network::get(),
+			id: supersig_account.into(),
+		})
+	}
+}
+
+/// Represents a supersig on other chains as the voice of the body numbered with its id, e.g.
+/// `../Parachain(1000)/GeneralIndex(id)/Plurality`
+pub struct SupersigAsPlurality;
+impl<AccountId> Convert<(SupersigId, AccountId), InteriorMultiLocation> for SupersigAsPlurality {
+	fn convert((supersig_id, _): (SupersigId, AccountId)) -> InteriorMultiLocation {
+		Junctions::X2(
+			Junction::GeneralIndex(supersig_id),
+			Junction::Plurality { id: BodyId::Unit, part: BodyPart::Voice },
+		)
+	}
+}
+
+/// A `fungible` currency seen as the only asset, `()`, of a set of `fungibles`, e.g. to pay the
+/// deposits in the native currency with `DepositAssets = FungibleAsAsset<Balances>` and
+/// `DepositAssetId = ()`.
+pub struct FungibleAsAsset<F>(PhantomData<F>);
+impl<AccountId, F: fungible::Inspect<AccountId>> fungibles::Inspect<AccountId>
+	for FungibleAsAsset<F>
+{
+	type AssetId = ();
+	type Balance = F::Balance;
+
+	fn total_issuance(_: ()) -> F::Balance {
+		F::total_issuance()
+	}
+
+	fn active_issuance(_: ()) -> F::Balance {
+		F::active_issuance()
+	}
+
+	fn minimum_balance(_: ()) -> F::Balance {
+		F::minimum_balance()
+	}
+
+	fn total_balance(_: (), who: &AccountId) -> F::Balance {
+		F::total_balance(who)
+	}
+
+	fn balance(_: (), who: &AccountId) -> F::Balance {
+		F::balance(who)
+	}
+
+	fn reducible_balance(
+		_: (),
+		who: &AccountId,
+		preservation: Preservation,
+		force: Fortitude,
+	) -> F::Balance {
+		F::reducible_balance(who, preservation, force)
+	}
+
+	fn can_deposit(
+		_: (),
+		who: &AccountId,
+		amount: F::Balance,
+		provenance: Provenance,
+	) -> DepositConsequence {
+		F::can_deposit(who, amount, provenance)
+	}
+
+	fn can_withdraw(_: (), who: &AccountId, amount: F::Balance) -> WithdrawConsequence<F::Balance> {
+		F::can_withdraw(who, amount)
+	}
+
+	fn asset_exists(_: ()) -> bool {
+		true
+	}
+}
+
+impl<AccountId, F: fungible::Unbalanced<AccountId>> fungibles::Unbalanced<AccountId>
+	for FungibleAsAsset<F>
+{
+	fn handle_dust(dust: fungibles::Dust<AccountId, Self>) {
+		F::handle_dust(fungible::Dust(dust.1))
+	}
+
+	fn write_balance(
+		_: (),
+		who: &AccountId,
+		amount: F::Balance,
+	) -> Result<Option<F::Balance>, DispatchError> {
+		F::write_balance(who, amount)
+	}
+
+	fn set_total_issuance(_: (), amount: F::Balance) {
+		F::set_total_issuance(amount)
+	}
+}
+
+impl<AccountId, F: fungible::Mutate<AccountId>> fungibles::Mutate<AccountId>
+	for FungibleAsAsset<F>
+{
+}
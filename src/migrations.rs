@@ -0,0 +1,177 @@
+//! Storage migrations for the supersig pallet.
+
+use crate::*;
+use frame_support::{
+	traits::{
+		Get, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+	use super::*;
+
+	/// Builds the `AccountSupersigs` reverse index from the `Members` storage.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+			Members::<T>::iter().for_each(|(supersig_id, member, _)| {
+				reads += 1;
+				writes += 1;
+				AccountSupersigs::<T>::insert(member, supersig_id, ());
+			});
+			log::info!(target: LOG_TARGET, "{} memberships indexed", writes - 1);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Converts the deposits reserved with `ReservableCurrency` into escrowed deposits.
+	///
+	/// `OldCurrency` is the currency the pallet reserved the deposits with, and the
+	/// `Config::DepositAssetId` asset must still be that same currency when it runs, e.g. with
+	/// `DepositAssets = FungibleAsAsset<Balances>`. Member deposits are escrowed from the
+	/// supersig accounts, and call deposits from the proposers accounts.
+	///
+	/// The supersig accounts are kept alive with a provider reference instead of a consumer one,
+	/// since the escrowed funds leave them. A deposit that can't be escrowed is left free, and
+	/// logged. Only the escrowed amounts are recorded, so no more than what is escrowed is
+	/// released later. The escrow account should already hold the minimum balance of the
+	/// deposit asset, otherwise the first deposit escrowed also pays it.
+	pub struct MigrateToV2<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+	impl<T, OldCurrency> MigrateToV2<T, OldCurrency>
+	where
+		T: Config,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = DepositBalanceOf<T>>,
+	{
+		// Returns the amount escrowed, which is what was left reserved of `amount`
+		fn reserve_to_escrow(
+			who: &T::AccountId,
+			amount: DepositBalanceOf<T>,
+		) -> Result<DepositBalanceOf<T>, DispatchError> {
+			let unreserved = amount.saturating_sub(OldCurrency::unreserve(who, amount));
+			Pallet::<T>::escrow_deposit(who, unreserved)?;
+			Ok(unreserved)
+		}
+	}
+
+	impl<T, OldCurrency> OnRuntimeUpgrade for MigrateToV2<T, OldCurrency>
+	where
+		T: Config,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = DepositBalanceOf<T>>,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 2u64;
+			let mut writes = 1u64;
+			for supersig_id in 0..Pallet::<T>::nonce_supersig() {
+				reads += 2;
+				if Pallet::<T>::supersig_exists(supersig_id) {
+					writes += 1;
+					let supersig_account = Pallet::<T>::supersig_account(supersig_id);
+					frame_system::Pallet::<T>::inc_providers(&supersig_account);
+					frame_system::Pallet::<T>::dec_consumers(&supersig_account);
+				}
+			}
+
+			let mut failures = 0u32;
+			TotalDeposit::<T>::translate(|supersig_id, deposit: DepositBalanceOf<T>| {
+				reads += 2;
+				writes += 3;
+				let supersig_account = Pallet::<T>::supersig_account(supersig_id);
+				match Self::reserve_to_escrow(&supersig_account, deposit) {
+					Ok(escrowed) => Some(escrowed),
+					Err(err) => {
+						log::warn!(
+							target: LOG_TARGET,
+							"member deposit of supersig {:?} can't be escrowed, left free: {:?}",
+							supersig_id,
+							err,
+						);
+						failures += 1;
+						None
+					},
+				}
+			});
+			Calls::<T>::translate(
+				|supersig_id,
+				 call_id,
+				 mut preimage: PreimageCall<T::AccountId, DepositBalanceOf<T>>| {
+					reads += 2;
+					writes += 3;
+					preimage.deposit = Self::reserve_to_escrow(&preimage.provider, preimage.deposit)
+						.unwrap_or_else(|err| {
+							log::warn!(
+								target: LOG_TARGET,
+								"call {:?} of supersig {:?}: deposit left free: {:?}",
+								call_id,
+								supersig_id,
+								err,
+							);
+							failures += 1;
+							Zero::zero()
+						});
+					Some(preimage)
+				},
+			);
+			log::info!(target: LOG_TARGET, "deposits escrowed, {} left free", failures);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Builds the `TotalMasters` count from the `Members` storage.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version >= 3 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+			Members::<T>::iter().for_each(|(supersig_id, _, role)| {
+				reads += 1;
+				if role == Role::Master {
+					reads += 1;
+					writes += 1;
+					TotalMasters::<T>::mutate(supersig_id, |masters| {
+						*masters = masters.saturating_add(1)
+					});
+				}
+			});
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
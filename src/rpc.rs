@@ -1,11 +1,29 @@
-use crate::Vec;
+use crate::{Dispatchable, Saturating, Vec};
 use codec::{Decode, Encode};
+pub use frame_support::{
+	dispatch::{DispatchClass, DispatchResultWithPostInfo},
+	weights::Weight,
+};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	ensure,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Get, GetCallMetadata},
+};
+use sp_core::hashing::blake2_256;
 use sp_runtime::DispatchError;
 pub use sp_std::{boxed::Box, cmp::max, mem::size_of};
+use sp_std::ops::Bound;
 
 use crate::pallet::{
-	CallId, Calls, Config, Error, Members, MembersVotes, Pallet, Role, SupersigId,
+	AccountSupersigs, Allowance, BalanceOf, CallId, Calls, Config, DepositBalanceOf, Error, Event,
+	ItemVotes, Members, MembersVotes, Pallet, PreimageCall, Role, SupersigId, TotalMembers,
+	VaultIndex, VotingMode, VotingModes,
+};
+use frame_support::traits::{
+	fungible::Inspect, fungibles::Inspect as _, tokens::nonfungibles_v2::Inspect as _,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 
 #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -44,24 +62,352 @@ impl<AccoutId: Clone> ProposalState<AccoutId> {
 	}
 }
 
+/// The state of a proposal, with its tally computed the way the pallet does when voting.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProposalStateV2<AccountId, Balance> {
+	id: CallId,
+	encoded_call: Vec<u8>,
+	/// blake2-256 hash of the encoded call
+	call_hash: [u8; 32],
+	/// dispatch weight of the call, zero if it can't be decoded anymore
+	call_weight: Weight,
+	provider: AccountId,
+	/// deposit escrowed from the provider
+	deposit: Balance,
+	/// the voters, with the vote weight they currently count for
+	voters: Vec<(AccountId, u32)>,
+	/// sum of the vote weight of the voters
+	tally: u32,
+	/// vote weight needed to execute the call
+	threshold: u32,
+}
+
+impl<AccountId, Balance> ProposalStateV2<AccountId, Balance> {
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		id: CallId,
+		encoded_call: Vec<u8>,
+		call_hash: [u8; 32],
+		call_weight: Weight,
+		provider: AccountId,
+		deposit: Balance,
+		voters: Vec<(AccountId, u32)>,
+		tally: u32,
+		threshold: u32,
+	) -> Self {
+		Self {
+			id,
+			encoded_call,
+			call_hash,
+			call_weight,
+			provider,
+			deposit,
+			voters,
+			tally,
+			threshold,
+		}
+	}
+
+	pub fn id(&self) -> &CallId {
+		&self.id
+	}
+
+	pub fn call_hash(&self) -> &[u8; 32] {
+		&self.call_hash
+	}
+
+	pub fn call_weight(&self) -> &Weight {
+		&self.call_weight
+	}
+
+	pub fn provider(&self) -> &AccountId {
+		&self.provider
+	}
+
+	pub fn deposit(&self) -> &Balance {
+		&self.deposit
+	}
+
+	pub fn voters(&self) -> &Vec<(AccountId, u32)> {
+		&self.voters
+	}
+
+	pub fn tally(&self) -> u32 {
+		self.tally
+	}
+
+	pub fn threshold(&self) -> u32 {
+		self.threshold
+	}
+}
+
+/// A proposed call, decoded, so that the voters can read what they approve.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallDescription {
+	encoded_call: Vec<u8>,
+	pallet_name: Vec<u8>,
+	function_name: Vec<u8>,
+	weight: Weight,
+	class: DispatchClass,
+}
+
+impl CallDescription {
+	pub fn new(
+		encoded_call: Vec<u8>,
+		pallet_name: Vec<u8>,
+		function_name: Vec<u8>,
+		weight: Weight,
+		class: DispatchClass,
+	) -> Self {
+		Self {
+			encoded_call,
+			pallet_name,
+			function_name,
+			weight,
+			class,
+		}
+	}
+
+	pub fn encoded_call(&self) -> &Vec<u8> {
+		&self.encoded_call
+	}
+
+	pub fn pallet_name(&self) -> &Vec<u8> {
+		&self.pallet_name
+	}
+
+	pub fn function_name(&self) -> &Vec<u8> {
+		&self.function_name
+	}
+
+	pub fn weight(&self) -> &Weight {
+		&self.weight
+	}
+
+	pub fn class(&self) -> &DispatchClass {
+		&self.class
+	}
+}
+
+/// The outcome of a proposed call, dispatched in a storage transaction that is rolled back.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct DryRunResult {
+	result: DispatchResultWithPostInfo,
+	actual_weight: Weight,
+	/// the SCALE encoded runtime events emitted by the call
+	events: Vec<Vec<u8>>,
+}
+
+impl DryRunResult {
+	pub fn new(
+		result: DispatchResultWithPostInfo,
+		actual_weight: Weight,
+		events: Vec<Vec<u8>>,
+	) -> Self {
+		Self {
+			result,
+			actual_weight,
+			events,
+		}
+	}
+
+	pub fn result(&self) -> &DispatchResultWithPostInfo {
+		&self.result
+	}
+
+	pub fn actual_weight(&self) -> &Weight {
+		&self.actual_weight
+	}
+
+	pub fn events(&self) -> &Vec<Vec<u8>> {
+		&self.events
+	}
+}
+
+/// How a call left the queue of a supersig.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum CallClosure {
+	/// the call was executed, with this result
+	Executed(Result<(), DispatchError>),
+	/// the call was removed without being executed
+	Removed,
+}
+
+/// What creating a supersig costs its creator.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositQuote<Balance> {
+	/// deposit escrowed from the supersig account, in the deposit asset
+	deposit: Balance,
+	/// amount transferred to the supersig account, in the deposit asset: the deposit, or the
+	/// minimum balance when it is higher, plus the minimum balance of the escrow while it is empty
+	transfer: Balance,
+}
+
+impl<Balance> DepositQuote<Balance> {
+	pub fn new(deposit: Balance, transfer: Balance) -> Self {
+		Self { deposit, transfer }
+	}
+
+	pub fn deposit(&self) -> &Balance {
+		&self.deposit
+	}
+
+	pub fn transfer(&self) -> &Balance {
+		&self.transfer
+	}
+}
+
+/// An overview of a supersig.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupersigInfo<AccountId, Balance, DepositBalance> {
+	account: AccountId,
+	id: SupersigId,
+	member_count: u32,
+	/// balance of the supersig account, in `Currency`, without the held funds
+	free_balance: Balance,
+	/// funds held on the supersig account, in `Currency`
+	reserved_balance: Balance,
+	/// member deposit escrowed from the supersig account, in the deposit asset
+	total_deposit: DepositBalance,
+	active_proposals: u32,
+	/// id of the next proposed call
+	nonce_call: CallId,
+}
+
+impl<AccountId, Balance, DepositBalance> SupersigInfo<AccountId, Balance, DepositBalance> {
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		account: AccountId,
+		id: SupersigId,
+		member_count: u32,
+		free_balance: Balance,
+		reserved_balance: Balance,
+		total_deposit: DepositBalance,
+		active_proposals: u32,
+		nonce_call: CallId,
+	) -> Self {
+		Self {
+			account,
+			id,
+			member_count,
+			free_balance,
+			reserved_balance,
+			total_deposit,
+			active_proposals,
+			nonce_call,
+		}
+	}
+
+	pub fn account(&self) -> &AccountId {
+		&self.account
+	}
+
+	pub fn id(&self) -> &SupersigId {
+		&self.id
+	}
+
+	pub fn member_count(&self) -> u32 {
+		self.member_count
+	}
+
+	pub fn free_balance(&self) -> &Balance {
+		&self.free_balance
+	}
+
+	pub fn reserved_balance(&self) -> &Balance {
+		&self.reserved_balance
+	}
+
+	pub fn total_deposit(&self) -> &DepositBalance {
+		&self.total_deposit
+	}
+
+	pub fn active_proposals(&self) -> u32 {
+		self.active_proposals
+	}
+
+	pub fn nonce_call(&self) -> &CallId {
+		&self.nonce_call
+	}
+}
+
+/// A supersig and its members. Members that are supersigs themselves come with their own tree.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupersigTree<AccountId> {
+	account: AccountId,
+	members: Vec<(AccountId, Role, Option<SupersigTree<AccountId>>)>,
+}
+
+impl<AccountId> SupersigTree<AccountId> {
+	pub fn new(
+		account: AccountId,
+		members: Vec<(AccountId, Role, Option<SupersigTree<AccountId>>)>,
+	) -> Self {
+		Self {
+			account,
+			members,
+		}
+	}
+
+	pub fn account(&self) -> &AccountId {
+		&self.account
+	}
+
+	pub fn members(&self) -> &Vec<(AccountId, Role, Option<SupersigTree<AccountId>>)> {
+		&self.members
+	}
+}
+
 impl<T: Config> Pallet<T> {
+	/// The supersigs the user is an explicit member of. Collection backed supersigs, whose
+	/// membership follows the items, are not listed. The whole index of the user is read, see
+	/// [`Self::get_user_supersigs_paged`] for a bounded read.
 	pub fn get_user_supersigs(user_account: &T::AccountId) -> Vec<SupersigId> {
-		Members::<T>::iter()
-			.filter_map(|(supersig_id, member_id, _)| {
-				if member_id == *user_account {
-					Some(supersig_id)
-				} else {
-					None
-				}
-			})
-			.collect()
+		AccountSupersigs::<T>::iter_key_prefix(user_account).collect()
+	}
+
+	/// A page of at most `limit` of the supersigs the user is an explicit member of, and never
+	/// more than [`MAX_PAGE_SIZE`], starting after the `start` cursor. The cursor of the next
+	/// page is returned, `None` once every supersig was listed.
+	pub fn get_user_supersigs_paged(
+		user_account: &T::AccountId,
+		start: Option<SupersigId>,
+		limit: u32,
+	) -> (Vec<SupersigId>, Option<SupersigId>) {
+		match start {
+			Some(start) => paginate(
+				AccountSupersigs::<T>::iter_key_prefix_from(
+					user_account,
+					AccountSupersigs::<T>::hashed_key_for(user_account, start),
+				),
+				limit,
+				|supersig_id| *supersig_id,
+			),
+			None => paginate(
+				AccountSupersigs::<T>::iter_key_prefix(user_account),
+				limit,
+				|supersig_id| *supersig_id,
+			),
+		}
 	}
 
 	pub fn list_members(
 		supersig_account: &T::AccountId,
 	) -> Result<Vec<(T::AccountId, Role)>, DispatchError> {
 		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
-		Ok(Members::<T>::iter_prefix(supersig_id).collect())
+		match Self::voting_mode(supersig_id) {
+			VotingMode::Collection(collection) => Ok(Self::collection_holders(&collection)
+				.into_iter()
+				.map(|holder| (holder, Role::Standard))
+				.collect()),
+			_ => Ok(Members::<T>::iter_prefix(supersig_id).collect()),
+		}
 	}
 
 	pub fn list_proposals(
@@ -70,21 +416,87 @@ impl<T: Config> Pallet<T> {
 		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
 		let member_count = Self::total_members(supersig_id);
 		let proposal_state = Calls::<T>::iter_prefix(supersig_id)
-			.map(|(call_id, call)| {
-				let voters = MembersVotes::<T>::iter_prefix((supersig_id, call_id))
-					.filter_map(
-						|(account_id, vote)| {
-							if vote { Some(account_id) } else { None }
-						},
-					)
-					.collect();
-
-				ProposalState::new(call_id, call.data, call.provider, voters)
-			})
+			.map(|(call_id, call)| Self::proposal_state(supersig_id, call_id, call))
 			.collect();
 		Ok((proposal_state, member_count))
 	}
 
+	/// A page of at most `limit` members, and never more than [`MAX_PAGE_SIZE`], starting after
+	/// the `start` cursor. The cursor of the next page is returned, `None` once every member was
+	/// listed.
+	pub fn list_members_paged(
+		supersig_account: &T::AccountId,
+		start: Option<T::AccountId>,
+		limit: u32,
+	) -> Result<(Vec<(T::AccountId, Role)>, Option<T::AccountId>), DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let page = match (Self::voting_mode(supersig_id), start) {
+			(VotingMode::Collection(collection), start) => {
+				// Holders are sorted, so the cursor is a key that still holds once its account
+				// stops holding items
+				let holders = Self::collection_holders(&collection);
+				let from = start.map_or(Bound::Unbounded, Bound::Excluded);
+				paginate(
+					holders
+						.range((from, Bound::Unbounded))
+						.map(|holder| (holder.clone(), Role::Standard)),
+					limit,
+					|(holder, _)| holder.clone(),
+				)
+			},
+			(_, Some(start)) => paginate(
+				Members::<T>::iter_prefix_from(
+					supersig_id,
+					Members::<T>::hashed_key_for(supersig_id, start),
+				),
+				limit,
+				|(member, _)| member.clone(),
+			),
+			(_, None) =>
+				paginate(Members::<T>::iter_prefix(supersig_id), limit, |(member, _)| {
+					member.clone()
+				}),
+		};
+		Ok(page)
+	}
+
+	/// A page of at most `limit` proposals, and never more than [`MAX_PAGE_SIZE`], starting after
+	/// the `start` cursor, with the number of members. The cursor of the next page is returned,
+	/// `None` once every proposal was listed.
+	pub fn list_proposals_paged(
+		supersig_account: &T::AccountId,
+		start: Option<CallId>,
+		limit: u32,
+	) -> Result<(Vec<ProposalState<T::AccountId>>, u32, Option<CallId>), DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let member_count = Self::total_members(supersig_id);
+		let calls = match start {
+			Some(start) => Calls::<T>::iter_prefix_from(
+				supersig_id,
+				Calls::<T>::hashed_key_for(supersig_id, start),
+			),
+			None => Calls::<T>::iter_prefix(supersig_id),
+		};
+		let (proposals, next) = paginate(
+			calls.map(|(call_id, call)| Self::proposal_state(supersig_id, call_id, call)),
+			limit,
+			|proposal| *proposal.id(),
+		);
+		Ok((proposals, member_count, next))
+	}
+
+	fn proposal_state(
+		supersig_id: SupersigId,
+		call_id: CallId,
+		call: PreimageCall<T::AccountId, DepositBalanceOf<T>>,
+	) -> ProposalState<T::AccountId> {
+		let voters = MembersVotes::<T>::iter_prefix((supersig_id, call_id))
+			.filter_map(|(account_id, vote)| if vote { Some(account_id) } else { None })
+			.collect();
+
+		ProposalState::new(call_id, call.data, call.provider, voters)
+	}
+
 	pub fn get_proposal_state(
 		supersig_account: &T::AccountId,
 		call_id: &CallId,
@@ -92,17 +504,414 @@ impl<T: Config> Pallet<T> {
 		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
 		let call = Self::calls(supersig_id, call_id).ok_or(Error::<T>::CallNotFound)?;
 		let member_count = Self::total_members(supersig_id);
+
+		Ok((Self::proposal_state(supersig_id, *call_id, call), member_count))
+	}
+
+	pub fn list_proposals_v2(
+		supersig_account: &T::AccountId,
+	) -> Result<Vec<ProposalStateV2<T::AccountId, DepositBalanceOf<T>>>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		Ok(Calls::<T>::iter_prefix(supersig_id)
+			.map(|(call_id, call)| Self::proposal_state_v2(supersig_id, call_id, call))
+			.collect())
+	}
+
+	pub fn get_proposal_state_v2(
+		supersig_account: &T::AccountId,
+		call_id: &CallId,
+	) -> Result<ProposalStateV2<T::AccountId, DepositBalanceOf<T>>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let call = Self::calls(supersig_id, call_id).ok_or(Error::<T>::CallNotFound)?;
+
+		Ok(Self::proposal_state_v2(supersig_id, *call_id, call))
+	}
+
+	/// The open proposals `member` can still vote for, with the account of the supersig they
+	/// belong to:
+	/// - in the supersigs it is an explicit member of, if it didn't vote yet,
+	/// - in the collection backed supersigs, if it holds snapshotted items that didn't vote yet,
+	/// - in the parents of the `Role` supersigs it is a member of, with `approve_call_as_child`,
+	///   if neither it nor the child supersig voted yet. Only the direct parents are listed.
+	/// Every collection backed supersig is read.
+	pub fn get_pending_for_member(
+		member: &T::AccountId,
+	) -> Vec<(T::AccountId, ProposalStateV2<T::AccountId, DepositBalanceOf<T>>)> {
+		let supersigs = Self::get_user_supersigs(member);
+		let mut pending: Vec<_> = supersigs
+			.iter()
+			.flat_map(|supersig_id| {
+				Self::pending_calls(*supersig_id, |call_id| {
+					!Self::members_votes((*supersig_id, call_id, member.clone()))
+				})
+			})
+			.collect();
+
+		VotingModes::<T>::iter().for_each(|(supersig_id, voting_mode)| {
+			if let VotingMode::Collection(collection) = voting_mode {
+				pending.extend(Self::pending_calls(supersig_id, |call_id| {
+					Self::unvoted_items(supersig_id, call_id, &collection, member).is_ok()
+				}));
+			}
+		});
+
+		for child_id in supersigs {
+			if Self::voting_mode(child_id) != VotingMode::Role {
+				continue
+			}
+			let child_account = Self::supersig_account(child_id);
+			for parent_id in Self::get_user_supersigs(&child_account) {
+				let calls = Self::pending_calls(parent_id, |call_id| {
+					!Self::members_votes((parent_id, call_id, child_account.clone())) &&
+						!Self::child_members_votes((parent_id, call_id, child_id, member.clone()))
+				});
+				// The member may also be an explicit member of the parent
+				for call in calls {
+					if !pending.contains(&call) {
+						pending.push(call);
+					}
+				}
+			}
+		}
+
+		pending
+	}
+
+	// The proposals of the supersig that `is_pending`, with the supersig account
+	fn pending_calls(
+		supersig_id: SupersigId,
+		is_pending: impl Fn(CallId) -> bool,
+	) -> Vec<(T::AccountId, ProposalStateV2<T::AccountId, DepositBalanceOf<T>>)> {
+		let supersig_account = Self::supersig_account(supersig_id);
+		Calls::<T>::iter_prefix(supersig_id)
+			.filter(|(call_id, _)| is_pending(*call_id))
+			.map(|(call_id, call)| {
+				(supersig_account.clone(), Self::proposal_state_v2(supersig_id, call_id, call))
+			})
+			.collect()
+	}
+
+	fn proposal_state_v2(
+		supersig_id: SupersigId,
+		call_id: CallId,
+		call: PreimageCall<T::AccountId, DepositBalanceOf<T>>,
+	) -> ProposalStateV2<T::AccountId, DepositBalanceOf<T>> {
 		let voters = MembersVotes::<T>::iter_prefix((supersig_id, call_id))
-			.filter_map(
-				|(account_id, vote)| {
-					if vote { Some(account_id) } else { None }
-				},
-			)
+			.filter_map(|(account_id, vote)| if vote { Some(account_id) } else { None })
+			.map(|voter| {
+				let weight = Self::voter_weight(supersig_id, call_id, &voter);
+				(voter, weight)
+			})
 			.collect();
+		let call_hash = blake2_256(&call.data);
+		let call_weight = <T as Config>::Call::decode(&mut &call.data[..])
+			.map(|decoded| decoded.get_dispatch_info().weight)
+			.unwrap_or_default();
+
+		ProposalStateV2::new(
+			call_id,
+			call.data,
+			call_hash,
+			call_weight,
+			call.provider,
+			call.deposit,
+			voters,
+			Self::votes(supersig_id, call_id),
+			Self::compute_threshold(supersig_id, call_id),
+		)
+	}
+
+	/// The pallet and function of a proposed call, with its dispatch info
+	pub fn get_call_description(
+		supersig_account: &T::AccountId,
+		call_id: &CallId,
+	) -> Result<CallDescription, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let call = Self::calls(supersig_id, call_id).ok_or(Error::<T>::CallNotFound)?;
+		let decoded = <T as Config>::Call::decode(&mut &call.data[..])
+			.map_err(|_| Error::<T>::BadEncodedCall)?;
+		let metadata = decoded.get_call_metadata();
+		let info = decoded.get_dispatch_info();
+
+		Ok(CallDescription::new(
+			call.data,
+			metadata.pallet_name.as_bytes().to_vec(),
+			metadata.function_name.as_bytes().to_vec(),
+			info.weight,
+			info.class,
+		))
+	}
+
+	/// Execute a proposed call as if the deciding vote was cast, and roll the changes back
+	pub fn dry_run_call(
+		supersig_account: &T::AccountId,
+		call_id: &CallId,
+	) -> Result<DryRunResult, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let preimage = Self::calls(supersig_id, call_id).ok_or(Error::<T>::CallNotFound)?;
+		Self::ensure_can_execute(supersig_id, &preimage.data)?;
+		let call = <T as Config>::Call::decode(&mut &preimage.data[..])
+			.map_err(|_| Error::<T>::BadEncodedCall)?;
+		let info = call.get_dispatch_info();
+
+		let total_votes =
+			max(Self::votes(supersig_id, *call_id), Self::compute_threshold(supersig_id, *call_id));
+		let (origin, dispatch_account) =
+			Self::dispatch_origin(supersig_id, supersig_account, *call_id, total_votes);
+
+		with_transaction(|| {
+			let events_before = frame_system::Pallet::<T>::event_count();
+			Self::unchecked_remove_call_from_storages(supersig_id, *call_id);
+			if let Err(err) = Self::release_proposal_deposit(&preimage) {
+				return TransactionOutcome::Rollback(Err(err))
+			}
+
+			let balance_before = T::Currency::total_balance(&dispatch_account);
+			let mut result = call.dispatch(origin);
+			let outflow =
+				balance_before.saturating_sub(T::Currency::total_balance(&dispatch_account));
+			if let Err(err) = Self::record_outflow(supersig_id, outflow) {
+				result = Err(DispatchError::from(err).into());
+			}
+
+			let actual_weight = match &result {
+				Ok(post_info) => post_info.calc_actual_weight(&info),
+				Err(err) => err.post_info.calc_actual_weight(&info),
+			};
+			let events = frame_system::Pallet::<T>::read_events_no_consensus()
+				.skip(events_before as usize)
+				.map(|record| record.event.encode())
+				.collect();
 
-		Ok((
-			ProposalState::new(*call_id, call.data, call.provider, voters),
+			TransactionOutcome::Rollback(Ok(DryRunResult::new(result, actual_weight, events)))
+		})
+	}
+
+	/// The deposit `create_supersig` would escrow for these members, and the amount it would
+	/// transfer from the creator
+	pub fn quote_create_supersig(
+		members: Vec<T::AccountId>,
+	) -> Result<DepositQuote<DepositBalanceOf<T>>, DispatchError> {
+		let deposit = Self::compute_deposit(size_of::<T::AccountId>() * distinct(members).len())?;
+		let transfer = max(T::DepositAssets::minimum_balance(T::DepositAssetId::get()), deposit)
+			.saturating_add(Self::escrow_shortfall());
+		Ok(DepositQuote::new(deposit, transfer))
+	}
+
+	/// The deposit `add_members` would escrow from the supersig account for these members. Those
+	/// already in the supersig are ignored, as `add_members` does.
+	pub fn quote_add_members(
+		supersig_account: &T::AccountId,
+		members: Vec<T::AccountId>,
+	) -> Result<DepositBalanceOf<T>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let new_members = distinct(members)
+			.into_iter()
+			.filter(|member| Self::members(supersig_id, member) == Role::NotMember)
+			.count();
+		Ok(Self::compute_deposit(size_of::<T::AccountId>() * new_members)?)
+	}
+
+	/// The deposit `propose_call` would escrow from the account of the proposer for this call
+	pub fn quote_propose_call(
+		encoded_call: Vec<u8>,
+	) -> Result<DepositBalanceOf<T>, DispatchError> {
+		ensure!(
+			encoded_call.len() <= T::MaxCallDataSize::get() as usize,
+			Error::<T>::CallDataTooLarge
+		);
+		Ok(Self::compute_deposit(encoded_call.len())?)
+	}
+
+	pub fn get_supersig_info(
+		supersig_account: &T::AccountId,
+	) -> Result<SupersigInfo<T::AccountId, BalanceOf<T>, DepositBalanceOf<T>>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		let member_count = match Self::voting_mode(supersig_id) {
+			VotingMode::Collection(collection) =>
+				u32::try_from(Self::collection_holders(&collection).len()).unwrap_or(u32::MAX),
+			_ => Self::total_members(supersig_id),
+		};
+		let free_balance = T::Currency::balance(supersig_account);
+		let reserved_balance =
+			T::Currency::total_balance(supersig_account).saturating_sub(free_balance);
+
+		Ok(SupersigInfo::new(
+			supersig_account.clone(),
+			supersig_id,
 			member_count,
+			free_balance,
+			reserved_balance,
+			Self::total_deposit(supersig_id),
+			Self::active_proposals(supersig_id),
+			Self::nonce_call(supersig_id),
 		))
 	}
+
+	/// A page of at most `limit` supersigs, and never more than [`MAX_PAGE_SIZE`], by id,
+	/// starting after the `start` cursor. At most [`MAX_SCANNED_IDS`] ids are read, so a page
+	/// can be short when many supersigs were deleted. The cursor of the next page, the last id
+	/// read, is returned, `None` once every supersig was listed.
+	pub fn list_supersigs(
+		start: Option<SupersigId>,
+		limit: u32,
+	) -> (Vec<(SupersigId, T::AccountId)>, Option<SupersigId>) {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let nonce = Self::nonce_supersig();
+		let first = start.map_or(0, |start| start.saturating_add(1));
+		let end = nonce.min(first.saturating_add(MAX_SCANNED_IDS.into()));
+
+		let mut page = Vec::new();
+		let mut next = start;
+		for supersig_id in first..end {
+			if page.len() == limit {
+				break
+			}
+			if Self::supersig_exists(supersig_id) {
+				page.push((supersig_id, Self::supersig_account(supersig_id)));
+			}
+			next = Some(supersig_id);
+		}
+		(page, next.filter(|next| next.saturating_add(1) < nonce))
+	}
+
+	/// The account of an existing supersig
+	pub fn get_supersig_account_by_id(supersig_id: SupersigId) -> Option<T::AccountId> {
+		Self::supersig_exists(supersig_id).then(|| Self::supersig_account(supersig_id))
+	}
+
+	/// The id of an existing supersig
+	pub fn get_supersig_id(supersig_account: &T::AccountId) -> Option<SupersigId> {
+		Self::get_supersig_id_from_account(supersig_account)
+			.ok()
+			.filter(|supersig_id| Self::supersig_exists(*supersig_id))
+	}
+
+	/// The calls of the supersig that left the queue in the current block, and how, read from
+	/// the events of the block
+	pub fn get_closed_calls(supersig_account: &T::AccountId) -> Vec<(CallId, CallClosure)>
+	where
+		<T as Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		frame_system::Pallet::<T>::read_events_no_consensus()
+			.filter_map(|record| {
+				match <T as Config>::RuntimeEvent::from(record.event).try_into().ok()? {
+					Event::<T>::CallExecutionAttempted(account, call_id, result)
+						if &account == supersig_account =>
+						Some((
+							call_id,
+							CallClosure::Executed(result.map(|_| ()).map_err(|err| err.error)),
+						)),
+					Event::<T>::CallRemoved(account, call_id) if &account == supersig_account =>
+						Some((call_id, CallClosure::Removed)),
+					_ => None,
+				}
+			})
+			.collect()
+	}
+
+	// Role and token weighted supersigs always have members, collection backed ones a voting
+	// mode. Both are removed with the supersig
+	pub(crate) fn supersig_exists(supersig_id: SupersigId) -> bool {
+		TotalMembers::<T>::contains_key(supersig_id) || VotingModes::<T>::contains_key(supersig_id)
+	}
+
+	// The weight a voter counts for: the weight of its role with the current members, its
+	// snapshotted weight, or the number of items it holds that voted
+	fn voter_weight(supersig_id: SupersigId, call_id: CallId, voter: &T::AccountId) -> u32 {
+		match Self::voting_mode(supersig_id) {
+			VotingMode::Role => {
+				let role = Self::members(supersig_id, voter);
+				Self::weight_of_role(&role, Self::total_members(supersig_id)).unwrap_or(0)
+			},
+			VotingMode::Token(_) =>
+				Self::vote_weight_snapshots((supersig_id, call_id, voter.clone())),
+			VotingMode::Collection(collection) => {
+				let items = ItemVotes::<T>::iter_key_prefix((supersig_id, call_id))
+					.filter(|item| T::Nfts::owner(&collection, item).as_ref() == Some(voter))
+					.count();
+				u32::try_from(items).unwrap_or(u32::MAX)
+			},
+		}
+	}
+
+	pub fn get_supersig_tree(
+		supersig_account: &T::AccountId,
+	) -> Result<SupersigTree<T::AccountId>, DispatchError> {
+		Self::build_supersig_tree(supersig_account, T::MaxNestingDepth::get())
+	}
+
+	fn build_supersig_tree(
+		supersig_account: &T::AccountId,
+		depth: u32,
+	) -> Result<SupersigTree<T::AccountId>, DispatchError> {
+		let members = Self::list_members(supersig_account)?
+			.into_iter()
+			.map(|(member, role)| {
+				// Cycles are rejected on membership changes, the depth is a safety net
+				let subtree = if depth > 0 && Self::get_supersig_id_from_account(&member).is_ok() {
+					Self::build_supersig_tree(&member, depth - 1).ok()
+				} else {
+					None
+				};
+				(member, role, subtree)
+			})
+			.collect();
+
+		Ok(SupersigTree::new(supersig_account.clone(), members))
+	}
+
+	pub fn get_allowance(
+		supersig_account: &T::AccountId,
+		member: &T::AccountId,
+	) -> Result<Option<Allowance<BalanceOf<T>, BlockNumberFor<T>>>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		Ok(Self::current_allowance(supersig_id, member))
+	}
+
+	/// The vaults of the supersig, with their account and balance
+	pub fn get_vault_balances(
+		supersig_account: &T::AccountId,
+	) -> Result<Vec<(VaultIndex, T::AccountId, BalanceOf<T>)>, DispatchError> {
+		let supersig_id = Self::get_supersig_id_from_account(supersig_account)?;
+		Ok((0..Self::vault_count(supersig_id))
+			.map(|vault| {
+				let vault_account = Self::vault_account(supersig_id, vault);
+				let balance = T::Currency::total_balance(&vault_account);
+				(vault, vault_account, balance)
+			})
+			.collect())
+	}
+}
+
+/// The largest page the paged getters return, a larger `limit` is lowered to it.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// The most supersig ids, deleted ones included, `list_supersigs` reads for a page.
+pub const MAX_SCANNED_IDS: u32 = 1_000;
+
+/// Take at most `limit` items, and the cursor of the next page: the key of the last item taken,
+/// if some items are left.
+fn paginate<I: Iterator, K>(
+	mut items: I,
+	limit: u32,
+	key: impl Fn(&I::Item) -> K,
+) -> (Vec<I::Item>, Option<K>) {
+	let limit = limit.min(MAX_PAGE_SIZE);
+	let page: Vec<I::Item> = items.by_ref().take(limit as usize).collect();
+	let next = match (page.last(), items.next()) {
+		(Some(last), Some(_)) => Some(key(last)),
+		_ => None,
+	};
+	(page, next)
+}
+
+fn distinct<Item: PartialEq>(items: Vec<Item>) -> Vec<Item> {
+	let mut distinct = Vec::with_capacity(items.len());
+	for item in items {
+		if !distinct.contains(&item) {
+			distinct.push(item);
+		}
+	}
+	distinct
 }
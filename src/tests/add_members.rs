@@ -32,7 +32,10 @@ fn add_members() {
 		let deposit = Balance::from(size_of::<<Test as frame_system::Config>::AccountId>() as u32)
 			.saturating_mul((Supersig::total_members(0) as u32).into())
 			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
-		assert_eq!(Balances::reserved_balance(get_supersig_account(0)), deposit);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			deposit + ExistentialDeposit::get()
+		);
 		assert_eq!(
 			last_event(),
 			RuntimeEvent::Supersig(crate::Event::MembersAdded(
@@ -75,3 +78,34 @@ fn add_users_unknown_supersig() {
 		);
 	})
 }
+
+#[test]
+fn add_member_of_many_supersigs() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// anyone can make an account the member of many supersigs
+		for _ in 0..10 {
+			assert_ok!(Supersig::create_supersig(
+				RawOrigin::Signed(ALICE()).into(),
+				vec![(BOB(), Role::Standard)].try_into().unwrap(),
+			));
+		}
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard)].try_into().unwrap(),
+		));
+		let supersig_account = get_supersig_account(10);
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			100_000
+		));
+
+		assert_ok!(Supersig::add_members(
+			RawOrigin::Signed(supersig_account).into(),
+			vec![(BOB(), Role::Standard)].try_into().unwrap()
+		));
+
+		assert_eq!(Supersig::members(10, BOB()), Role::Standard);
+		assert_eq!(Supersig::get_user_supersigs(&BOB()).len(), 11);
+	})
+}
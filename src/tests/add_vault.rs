@@ -0,0 +1,171 @@
+use super::{helper::*, mock::*};
+use crate::{CallOrigin, Error, Inheritance, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+#[test]
+fn add_vault() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 0);
+		assert_ok!(Supersig::add_vault(
+			RawOrigin::Signed(supersig_account.clone()).into()
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			Supersig::vault_account(0, 0),
+			100_000
+		));
+
+		assert_eq!(Supersig::vault_count(0), 1);
+		assert_ne!(Supersig::vault_account(0, 0), supersig_account);
+		assert_ne!(Supersig::vault_account(0, 0), Supersig::vault_account(0, 1));
+		assert_ne!(Supersig::vault_account(0, 0), Supersig::vault_account(1, 0));
+		assert_noop!(
+			Supersig::get_supersig_id_from_account(&Supersig::vault_account(0, 0)),
+			Error::<Test>::NotSupersig
+		);
+
+		assert_ok!(Supersig::add_vault(
+			RawOrigin::Signed(supersig_account.clone()).into()
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::VaultAdded(
+				supersig_account.clone(),
+				1,
+				Supersig::vault_account(0, 1)
+			))
+		);
+
+		assert_noop!(
+			Supersig::add_vault(RawOrigin::Signed(supersig_account).into()),
+			Error::<Test>::TooManyVaults
+		);
+		assert_noop!(
+			Supersig::add_vault(RawOrigin::Signed(ALICE()).into()),
+			Error::<Test>::NotSupersig
+		);
+	})
+}
+
+#[test]
+fn propose_call_from_vault() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 0);
+		assert_ok!(Supersig::add_vault(
+			RawOrigin::Signed(supersig_account.clone()).into()
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			Supersig::vault_account(0, 0),
+			100_000
+		));
+		let vault_account = Supersig::vault_account(0, 0);
+		let supersig_balance = Balances::free_balance(&supersig_account);
+		let paul_balance = Balances::free_balance(PAUL());
+
+		let call: RuntimeCall = pallet_balances::Call::transfer {
+			dest: PAUL(),
+			value: 10_000,
+		}
+		.into();
+		assert_noop!(
+			Supersig::propose_call_with_origin(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				Box::new(call.clone()),
+				CallOrigin::Vault(1),
+				None
+			),
+			Error::<Test>::VaultNotFound
+		);
+		assert_ok!(Supersig::propose_call_with_origin(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call),
+			CallOrigin::Vault(0),
+			None
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_eq!(Balances::free_balance(&vault_account), 90_000);
+		assert_eq!(Balances::free_balance(&supersig_account), supersig_balance);
+		assert_eq!(Balances::free_balance(PAUL()), paul_balance + 10_000);
+	})
+}
+
+#[test]
+fn delete_supersig_sweeps_vaults() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 0);
+		assert_ok!(Supersig::add_vault(
+			RawOrigin::Signed(supersig_account.clone()).into()
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			Supersig::vault_account(0, 0),
+			100_000
+		));
+		let vault_account = Supersig::vault_account(0, 0);
+		let supersig_balance = Balances::total_balance(&supersig_account);
+		let paul_balance = Balances::free_balance(PAUL());
+
+		assert_eq!(
+			Supersig::get_vault_balances(&supersig_account),
+			Ok(vec![(0, vault_account.clone(), 100_000)])
+		);
+
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			PAUL()
+		));
+
+		assert_eq!(Supersig::vault_count(0), 0);
+		assert_eq!(Balances::total_balance(&vault_account), 0);
+		assert_eq!(
+			Balances::free_balance(PAUL()),
+			paul_balance + supersig_balance + 100_000
+		);
+		assert!(Supersig::get_vault_balances(&supersig_account).is_err());
+	})
+}
+
+#[test]
+fn set_inheritance_keeps_vaults() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 0);
+		assert_ok!(Supersig::add_vault(
+			RawOrigin::Signed(supersig_account.clone()).into()
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			Supersig::vault_account(0, 0),
+			100_000
+		));
+		let vault_account = Supersig::vault_account(0, 0);
+
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(Inheritance {
+				beneficiary: PAUL(),
+				inactivity_period: 100,
+				challenge_period: 10,
+			})
+		));
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			None
+		));
+
+		assert_eq!(Supersig::vault_count(0), 1);
+		assert_eq!(
+			Supersig::get_vault_balances(&supersig_account),
+			Ok(vec![(0, vault_account, 100_000)])
+		);
+	})
+}
@@ -1,6 +1,6 @@
 use super::{helper::*, mock::*};
-use crate::{Error, Role};
-use frame_support::{assert_noop, assert_ok};
+use crate::{weights::WeightInfo, Error, GetDispatchInfo, Role};
+use frame_support::{assert_noop, assert_ok, traits::Get};
 use frame_system::RawOrigin;
 pub use sp_std::boxed::Box;
 
@@ -103,7 +103,10 @@ fn approve_call_until_threshold() {
 		assert!(!Supersig::members_votes((0, 0, CHARLIE())));
 
 		assert!(Supersig::calls(0, 0).is_none());
-		assert_eq!(Balances::reserved_balance(ALICE()), 0);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			Supersig::total_deposit(0) + ExistentialDeposit::get()
+		);
 		let supersig_event = last_event();
 
 		assert_eq!(bob_balance + 100_000, Balances::free_balance(BOB()));
@@ -179,7 +182,10 @@ fn approve_call_as_master() {
 		assert!(!Supersig::members_votes((0, 0, CHARLIE())));
 
 		assert!(Supersig::calls(0, 0).is_none());
-		assert_eq!(Balances::reserved_balance(ALICE()), 0);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			Supersig::total_deposit(0) + ExistentialDeposit::get()
+		);
 
 		let supersig_event = last_event();
 
@@ -251,7 +257,10 @@ fn approve_failing_call_as_master() {
 		assert!(!Supersig::members_votes((0, 0, CHARLIE())));
 
 		assert!(Supersig::calls(0, 0).is_none());
-		assert_eq!(Balances::reserved_balance(ALICE()), 0);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			Supersig::total_deposit(0) + ExistentialDeposit::get()
+		);
 
 		let supersig_event = last_event();
 
@@ -363,3 +372,52 @@ fn approve_not_a_member() {
 		);
 	})
 }
+
+#[test]
+fn approval_is_charged_the_weight_it_used() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			}
+			.try_into()
+			.unwrap(),
+		));
+		let supersig_account = get_supersig_account(0);
+		// The supersig can't pay it, the call fails
+		let call: RuntimeCall = pallet_balances::Call::transfer {
+			dest: BOB(),
+			value: 1_000_000_000,
+		}
+		.into();
+		let call_weight = call.get_dispatch_info().weight;
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		let vote_weight = <Test as crate::Config>::WeightInfo::approve_call();
+
+		let post_info = Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0,
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(Supersig::approval_weight(vote_weight, None)));
+
+		let post_info =
+			Supersig::approve_call(RawOrigin::Signed(BOB()).into(), supersig_account, 0).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(Supersig::approval_weight(vote_weight, Some(call_weight)))
+		);
+		assert!(post_info.actual_weight.unwrap().all_lte(Supersig::approval_weight(
+			vote_weight,
+			Some(MaxCallWeight::get())
+		)));
+	})
+}
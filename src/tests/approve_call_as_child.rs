@@ -0,0 +1,314 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+#[test]
+fn approve_call_as_child() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 (ALICE, BOB, CHARLIE) is a member of supersig 1 (supersig 0, PAUL)
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account.clone(),
+			parent_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::child_votes((1, 0, 0)), 1);
+		assert!(Supersig::child_members_votes((1, 0, 0, ALICE())));
+		assert_eq!(Supersig::pending_child_votes(0, ALICE()), 1);
+		assert_eq!(Supersig::votes(1, 0), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::ChildCallVoted(
+				child_account.clone(),
+				parent_account.clone(),
+				0,
+				ALICE()
+			))
+		);
+
+		// the child threshold is reached, the child votes in the parent
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(BOB()).into(),
+			child_account.clone(),
+			parent_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::child_votes((1, 0, 0)), 0);
+		assert!(!Supersig::child_members_votes((1, 0, 0, ALICE())));
+		assert_eq!(Supersig::pending_child_votes(0, ALICE()), 0);
+		assert_eq!(Supersig::votes(1, 0), 1);
+		assert!(Supersig::members_votes((1, 0, child_account.clone())));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallVoted(parent_account, 0, child_account))
+		);
+	})
+}
+
+#[test]
+fn approve_call_as_child_executes_parent_call() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 (ALICE, BOB, CHARLIE) is a member of supersig 1 (supersig 0, PAUL)
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			0
+		));
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account.clone(),
+			parent_account.clone(),
+			0
+		));
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(CHARLIE()).into(),
+			child_account,
+			parent_account,
+			0
+		));
+		assert!(Supersig::calls(1, 0).is_none());
+	})
+}
+
+#[test]
+fn approve_call_as_child_twice() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 (ALICE, BOB, CHARLIE) is a member of supersig 1 (supersig 0, PAUL)
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account.clone(),
+			parent_account.clone(),
+			0
+		));
+		assert_noop!(
+			Supersig::approve_call_as_child(
+				RawOrigin::Signed(ALICE()).into(),
+				child_account,
+				parent_account,
+				0
+			),
+			Error::<Test>::AlreadyVoted
+		);
+	})
+}
+
+#[test]
+fn approve_call_as_child_not_a_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 (ALICE, BOB, CHARLIE) is a member of supersig 1 (supersig 0, PAUL)
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_noop!(
+			Supersig::approve_call_as_child(
+				RawOrigin::Signed(PAUL()).into(),
+				child_account.clone(),
+				parent_account.clone(),
+				0
+			),
+			Error::<Test>::NotMember
+		);
+		// the parent is not a member of the child
+		assert_noop!(
+			Supersig::approve_call_as_child(
+				RawOrigin::Signed(PAUL()).into(),
+				parent_account,
+				child_account,
+				0
+			),
+			Error::<Test>::CallNotFound
+		);
+	})
+}
+
+#[test]
+fn nested_membership_cycle() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 (ALICE, BOB, CHARLIE) is a member of supersig 1 (supersig 0, PAUL)
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account.clone(),
+			100_000
+		));
+
+		assert_noop!(
+			Supersig::add_members(
+				RawOrigin::Signed(child_account.clone()).into(),
+				vec!((parent_account.clone(), Role::Standard)).try_into().unwrap()
+			),
+			Error::<Test>::MembershipCycle
+		);
+		assert_noop!(
+			Supersig::add_members(
+				RawOrigin::Signed(child_account.clone()).into(),
+				vec!((child_account.clone(), Role::Standard)).try_into().unwrap()
+			),
+			Error::<Test>::MembershipCycle
+		);
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(ALICE()).into(),
+				child_account,
+				ALICE(),
+				parent_account
+			),
+			Error::<Test>::MembershipCycle
+		);
+	})
+}
+
+#[test]
+fn nested_members_visits_are_bounded() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersigs 0 and 1 have 4 members each
+		for _ in 0..2 {
+			assert_ok!(Supersig::create_supersig(
+				RawOrigin::Signed(ALICE()).into(),
+				vec! {
+					(ALICE(), Role::Standard),
+					(BOB(), Role::Standard),
+					(CHARLIE(), Role::Standard),
+					(PAUL(), Role::Standard),
+				}
+				.try_into()
+				.unwrap()
+			));
+		}
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(get_supersig_account(0), Role::Standard),
+				(get_supersig_account(1), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+
+		// supersig 2 has 10 nested members, more than MaxNestingVisits
+		assert_noop!(
+			Supersig::create_supersig(
+				RawOrigin::Signed(ALICE()).into(),
+				vec!((get_supersig_account(2), Role::Standard)).try_into().unwrap()
+			),
+			Error::<Test>::NestingTooDeep
+		);
+	})
+}
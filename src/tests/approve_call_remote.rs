@@ -0,0 +1,371 @@
+use super::{
+	helper::*,
+	mock::*,
+	xcm_mock::{remote, MockNet, ParaA, ParaB},
+};
+use crate::{Error, Role};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, weights::Weight};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError;
+pub use sp_std::boxed::Box;
+use xcm::latest::prelude::*;
+use xcm_simulator::TestExt;
+
+// The location of DONALD on `ParaB`, as seen from `ParaA`
+fn remote_donald() -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X2(
+			Parachain(2),
+			AccountId32 {
+				network: None,
+				id: DONALD().into(),
+			},
+		),
+	)
+}
+
+#[test]
+fn set_remote_voter() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Supersig(crate::Event::RemoteVoterSet(
+				supersig_account.clone(),
+				remote_donald(),
+				Some(BOB())
+			))));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), Some(BOB()));
+
+		assert_noop!(
+			Supersig::set_remote_voter(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				Box::new(remote_donald().into()),
+				Some(PAUL())
+			),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			Supersig::set_remote_voter(
+				RawOrigin::Signed(ALICE()).into(),
+				Box::new(remote_donald().into()),
+				Some(BOB())
+			),
+			Error::<Test>::NotSupersig
+		);
+
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			None
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::RemoteVoterSet(
+				supersig_account,
+				remote_donald(),
+				None
+			))
+		);
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), None);
+	})
+}
+
+#[test]
+fn approve_call_remote() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::approve_call_remote(
+			pallet_xcm::Origin::Xcm(remote_donald()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::votes(0, 0), 1);
+		assert!(Supersig::members_votes((0, 0, BOB())));
+		assert!(!Supersig::members_votes((0, 0, DONALD())));
+
+		assert_noop!(
+			Supersig::approve_call_remote(
+				pallet_xcm::Origin::Xcm(remote_donald()).into(),
+				supersig_account,
+				0
+			),
+			Error::<Test>::AlreadyVoted
+		);
+	})
+}
+
+#[test]
+fn approve_call_remote_unknown_voter() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		let location = MultiLocation::new(1, X1(Parachain(2)));
+
+		assert_noop!(
+			Supersig::approve_call_remote(
+				pallet_xcm::Origin::Xcm(location).into(),
+				supersig_account.clone(),
+				0
+			),
+			Error::<Test>::UnknownRemoteVoter
+		);
+		assert_noop!(
+			Supersig::approve_call_remote(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account,
+				0
+			),
+			DispatchError::BadOrigin
+		);
+	})
+}
+
+#[test]
+fn approve_call_remote_through_xcm() {
+	MockNet::reset();
+
+	let supersig_account = ParaA::execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		supersig_account
+	});
+
+	ParaB::execute_with(|| {
+		let call: RuntimeCall = crate::Call::approve_call_remote {
+			supersig_account: supersig_account.clone(),
+			call_id: 0,
+		}
+		.into();
+		let message = Xcm(vec![
+			DescendOrigin(X1(AccountId32 {
+				network: None,
+				id: DONALD().into(),
+			})),
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				// The approval is charged MaxCallWeight, in case it executes the call
+				require_weight_at_most: Weight::from_parts(2_000_000_000_000, 2 * 1024 * 1024),
+				call: call.encode().into(),
+			},
+		]);
+		assert_ok!(send_xcm::<remote::XcmRouter>(
+			(Parent, Parachain(1)).into(),
+			message
+		));
+	});
+
+	ParaA::execute_with(|| {
+		assert_eq!(Supersig::votes(0, 0), 1);
+		assert!(Supersig::members_votes((0, 0, BOB())));
+	});
+}
+
+#[test]
+fn remote_voter_removed_with_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+
+		// unrelated changes keep the remote voter
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			None
+		));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), Some(BOB()));
+
+		assert_ok!(Supersig::leave_supersig(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone()
+		));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), None);
+		assert_noop!(
+			Supersig::approve_call_remote(
+				pallet_xcm::Origin::Xcm(remote_donald()).into(),
+				supersig_account,
+				0
+			),
+			Error::<Test>::UnknownRemoteVoter
+		);
+	})
+}
+
+#[test]
+fn remote_voter_removed_on_rotation() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			BOB(),
+			PAUL()
+		));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), None);
+
+		// the location can be set again for the new key
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(PAUL())
+		));
+		assert_ok!(Supersig::remove_members(
+			RawOrigin::Signed(supersig_account).into(),
+			vec![PAUL()].try_into().unwrap()
+		));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), None);
+	})
+}
+
+#[test]
+fn remote_voters_removed_with_supersig() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_remote_voter(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Box::new(remote_donald().into()),
+			Some(BOB())
+		));
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(supersig_account).into(),
+			PAUL()
+		));
+		assert_eq!(Supersig::remote_voters(0, remote_donald()), None);
+		assert_eq!(crate::RemoteVoterLocations::<Test>::iter().count(), 0);
+	})
+}
@@ -24,7 +24,10 @@ fn create_supersig() {
 			.saturating_mul((3u32).into())
 			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
 
-		assert_eq!(Balances::reserved_balance(get_supersig_account(0)), deposit);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			deposit + ExistentialDeposit::get()
+		);
 		assert_eq!(Supersig::nonce_supersig(), 1);
 		assert_eq!(Supersig::members(0, ALICE()), Role::Standard);
 		assert_eq!(Supersig::members(0, BOB()), Role::Standard);
@@ -69,7 +72,10 @@ fn create_supersig_with_master() {
 			.saturating_mul((3u32).into())
 			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
 
-		assert_eq!(Balances::reserved_balance(get_supersig_account(0)), deposit);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			deposit + ExistentialDeposit::get()
+		);
 		assert_eq!(Supersig::nonce_supersig(), 1);
 		assert_eq!(Supersig::members(0, ALICE()), Role::Standard);
 		assert_eq!(Supersig::members(0, BOB()), Role::Master);
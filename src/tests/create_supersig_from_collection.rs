@@ -0,0 +1,255 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Role, VotingMode};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+const COLLECTION: u32 = 0;
+
+// BOB holds items 0 and 1, CHARLIE item 2
+fn mint_collection() {
+	assert_ok!(Nfts::force_create(
+		RuntimeOrigin::root(),
+		ALICE(),
+		pallet_nfts::CollectionConfig {
+			settings: pallet_nfts::CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: pallet_nfts::MintSettings::default(),
+		}
+	));
+	for (item, owner) in [(0, BOB()), (1, BOB()), (2, CHARLIE())] {
+		assert_ok!(Nfts::mint(RawOrigin::Signed(ALICE()).into(), COLLECTION, item, owner, None));
+	}
+}
+
+fn propose_remark(supersig_account: &sp_runtime::AccountId32) {
+	let call = frame_system::Call::remark {
+		remark: "test".into(),
+	};
+	assert_ok!(Supersig::propose_call(
+		RawOrigin::Signed(CHARLIE()).into(),
+		supersig_account.clone(),
+		Box::new(call.into())
+	));
+}
+
+#[test]
+fn create_supersig_from_collection() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+
+		assert_eq!(Supersig::nonce_supersig(), 1);
+		assert_eq!(Supersig::total_members(0), 0);
+		assert_eq!(Supersig::voting_mode(0), VotingMode::Collection(COLLECTION));
+		assert_eq!(Supersig::get_supersig_id_from_account(&supersig_account), Ok(0));
+		assert!(Supersig::is_member(0, &BOB()));
+		assert!(!Supersig::is_member(0, &ALICE()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::VotingModeSet(
+				supersig_account.clone(),
+				VotingMode::Collection(COLLECTION)
+			))
+		);
+
+		let members = Supersig::list_members(&supersig_account).unwrap();
+		assert_eq!(members.len(), 2);
+		assert!(members.contains(&(BOB(), Role::Standard)));
+		assert!(members.contains(&(CHARLIE(), Role::Standard)));
+	})
+}
+
+#[test]
+fn collection_holders_vote() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+		propose_remark(&supersig_account);
+
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(ALICE()).into(), supersig_account.clone(), 0),
+			Error::<Test>::NotMember
+		);
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::votes(0, 0), 1);
+		assert!(Supersig::item_votes((0, 0, 2)));
+
+		// BOB holds 2 of the 3 items
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert!(Supersig::calls(0, 0).is_none());
+		assert!(!Supersig::item_votes((0, 0, 2)));
+	})
+}
+
+#[test]
+fn transferring_an_item_transfers_the_seat() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+		propose_remark(&supersig_account);
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_ok!(Nfts::transfer(RawOrigin::Signed(CHARLIE()).into(), COLLECTION, 2, PAUL()));
+		assert!(!Supersig::is_member(0, &CHARLIE()));
+		assert!(Supersig::is_member(0, &PAUL()));
+
+		// the item already voted
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(PAUL()).into(), supersig_account.clone(), 0),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(CHARLIE()).into(), supersig_account, 0),
+			Error::<Test>::NotMember
+		);
+	})
+}
+
+#[test]
+fn list_holders_paged() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+		let mut holders = vec![(BOB(), Role::Standard), (CHARLIE(), Role::Standard)];
+		holders.sort();
+
+		let (first, next) = Supersig::list_members_paged(&supersig_account, None, 1).unwrap();
+		assert_eq!(first, holders[..1].to_vec());
+		assert_eq!(next, Some(holders[0].0.clone()));
+		let (second, next) = Supersig::list_members_paged(&supersig_account, next, 1).unwrap();
+		assert_eq!(second, holders[1..].to_vec());
+		assert_eq!(next, None);
+
+		// a cursor holding no item pages from where it sorts, instead of from the start
+		let after_alice: Vec<_> =
+			holders.iter().filter(|(holder, _)| *holder > ALICE()).cloned().collect();
+		assert_eq!(
+			Supersig::list_members_paged(&supersig_account, Some(ALICE()), 3),
+			Ok((after_alice, None))
+		);
+	})
+}
+
+#[test]
+fn item_count_is_snapshotted() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+		propose_remark(&supersig_account);
+		assert_eq!(Supersig::total_weight_snapshots(0, 0), 3);
+
+		// the items minted after the call was proposed don't raise its threshold
+		for item in [3, 4] {
+			assert_ok!(Nfts::mint(
+				RawOrigin::Signed(ALICE()).into(),
+				COLLECTION,
+				item,
+				PAUL(),
+				None
+			));
+		}
+		// nor vote for it
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(PAUL()).into(), supersig_account.clone(), 0),
+			Error::<Test>::NotMember
+		);
+		let mut snapshot = Supersig::item_snapshots(0, 0).into_inner();
+		snapshot.sort();
+		assert_eq!(snapshot, vec![0, 1, 2]);
+
+		// BOB holds 2 of the 3 snapshotted items
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert!(Supersig::calls(0, 0).is_none());
+		assert_eq!(Supersig::total_weight_snapshots(0, 0), 0);
+		assert!(Supersig::item_snapshots(0, 0).is_empty());
+	})
+}
+
+#[test]
+fn propose_call_fails_with_too_many_items() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+		// MaxSnapshotSize is 4
+		for item in [3, 4] {
+			assert_ok!(Nfts::mint(
+				RawOrigin::Signed(ALICE()).into(),
+				COLLECTION,
+				item,
+				PAUL(),
+				None
+			));
+		}
+
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_noop!(
+			Supersig::propose_call(
+				RawOrigin::Signed(CHARLIE()).into(),
+				supersig_account,
+				Box::new(call.into())
+			),
+			Error::<Test>::SnapshotTooLarge
+		);
+	})
+}
+
+#[test]
+fn collection_supersig_needs_members_to_change_mode() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_collection();
+		assert_ok!(Supersig::create_supersig_from_collection(
+			RawOrigin::Signed(ALICE()).into(),
+			COLLECTION
+		));
+		let supersig_account = get_supersig_account(0);
+
+		assert_noop!(
+			Supersig::set_voting_mode(RawOrigin::Signed(supersig_account).into(), VotingMode::Role),
+			Error::<Test>::MustHaveAtLeastOneMember
+		);
+	})
+}
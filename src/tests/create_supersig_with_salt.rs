@@ -0,0 +1,143 @@
+use super::{helper::*, mock::*};
+use crate::{Config as SuperConfig, Error, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::{boxed::Box, mem::size_of};
+
+const SALT: [u8; 32] = [7u8; 32];
+
+#[test]
+fn create_supersig_with_salt() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = Supersig::salted_supersig_account(&ALICE(), &SALT);
+		assert_ne!(supersig_account, get_supersig_account(0));
+
+		// the account can be funded before the supersig exists
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			10_000
+		));
+
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+
+		let deposit = Balance::from(size_of::<<Test as frame_system::Config>::AccountId>() as u32)
+			.saturating_mul((2u32).into())
+			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			deposit + ExistentialDeposit::get()
+		);
+		assert_eq!(Balances::free_balance(&supersig_account), 10_000);
+		assert_eq!(Supersig::nonce_supersig(), 1);
+		assert_eq!(Supersig::salted_supersigs(&supersig_account), Some(0));
+		assert_eq!(Supersig::supersig_account(0), supersig_account);
+		assert_eq!(Supersig::get_supersig_id_from_account(&supersig_account), Ok(0));
+		assert_eq!(Supersig::members(0, BOB()), Role::Standard);
+
+		// the account derived from the id doesn't resolve to the salted supersig
+		assert_noop!(
+			Supersig::get_supersig_id_from_account(&get_supersig_account(0)),
+			Error::<Test>::NotSupersig
+		);
+	})
+}
+
+#[test]
+fn salted_supersig_calls() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+		let supersig_account = Supersig::salted_supersig_account(&ALICE(), &SALT);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account,
+				0,
+				Ok(())
+			))
+		);
+	})
+}
+
+#[test]
+fn salt_already_used() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+		assert_noop!(
+			Supersig::create_supersig_with_salt(
+				RawOrigin::Signed(ALICE()).into(),
+				vec![(BOB(), Role::Standard)].try_into().unwrap(),
+				SALT
+			),
+			Error::<Test>::SaltAlreadyUsed
+		);
+
+		// the salt is bound to its creator
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(BOB()).into(),
+			vec![(BOB(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+		assert_eq!(
+			Supersig::get_supersig_id_from_account(&Supersig::salted_supersig_account(
+				&BOB(),
+				&SALT
+			)),
+			Ok(1)
+		);
+	})
+}
+
+#[test]
+fn salt_reusable_after_delete() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+		let supersig_account = Supersig::salted_supersig_account(&ALICE(), &SALT);
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			BOB()
+		));
+		assert_eq!(Supersig::salted_supersigs(&supersig_account), None);
+		assert_eq!(Supersig::salted_accounts(0), None);
+		assert_noop!(
+			Supersig::get_supersig_id_from_account(&supersig_account),
+			Error::<Test>::NotSupersig
+		);
+
+		assert_ok!(Supersig::create_supersig_with_salt(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Standard)].try_into().unwrap(),
+			SALT
+		));
+		assert_eq!(Supersig::get_supersig_id_from_account(&supersig_account), Ok(1));
+	})
+}
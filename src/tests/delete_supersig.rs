@@ -103,7 +103,7 @@ fn delete_supersig_with_call() {
 			Balances::free_balance(BOB()),
 			bob_balance + amount + reserve
 		);
-		assert_eq!(Balances::reserved_balance(BOB()), 0);
+		assert_eq!(Balances::free_balance(Supersig::deposit_account()), ExistentialDeposit::get());
 		assert_eq!(
 			last_event(),
 			RuntimeEvent::Supersig(crate::Event::SupersigRemoved(supersig_account))
@@ -0,0 +1,151 @@
+use super::deposit_mock::*;
+use crate::{Config as SuperConfig, Role};
+use codec::Encode;
+use frame_support::{assert_ok, traits::fungibles::Inspect};
+use frame_system::RawOrigin;
+pub use sp_std::{boxed::Box, mem::size_of};
+
+fn member_deposit(members: u32) -> StableBalance {
+	StableBalance::from(size_of::<AccountId>() as u32)
+		.saturating_mul(members.into())
+		.saturating_mul(<Test as SuperConfig>::DepositPerByte::get())
+}
+
+fn stable_balance(who: &AccountId) -> StableBalance {
+	<Assets as Inspect<AccountId>>::balance(STABLECOIN, who)
+}
+
+// The minimum balance the escrow keeps, paid by the first deposit
+fn escrow_floor() -> StableBalance {
+	<Assets as Inspect<AccountId>>::minimum_balance(STABLECOIN)
+}
+
+fn create_supersig(members: Vec<AccountId>) -> AccountId {
+	assert_ok!(Supersig::create_supersig(
+		RawOrigin::Signed(ALICE()).into(),
+		members
+			.into_iter()
+			.map(|member| (member, Role::Standard))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap(),
+	));
+	get_supersig_account(0)
+}
+
+#[test]
+fn create_supersig_escrows_deposit_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let native_balance = Balances::free_balance(ALICE());
+		let alice_stable_balance = stable_balance(&ALICE());
+
+		let supersig_account = create_supersig(vec![ALICE(), BOB(), CHARLIE()]);
+
+		let deposit = member_deposit(3);
+		assert_eq!(stable_balance(&Supersig::deposit_account()), deposit + escrow_floor());
+		assert_eq!(stable_balance(&ALICE()), alice_stable_balance - deposit - escrow_floor());
+		assert_eq!(stable_balance(&supersig_account), 0);
+		assert_eq!(Supersig::total_deposit(0), deposit);
+		assert_eq!(Balances::free_balance(ALICE()), native_balance);
+		assert_eq!(Balances::free_balance(&supersig_account), 0);
+	});
+}
+
+#[test]
+fn add_members_escrows_deposit_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let supersig_account = create_supersig(vec![ALICE(), BOB()]);
+		assert_ok!(Assets::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			STABLECOIN.into(),
+			supersig_account.clone(),
+			10_000
+		));
+
+		assert_ok!(Supersig::add_members(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			vec![(CHARLIE(), Role::Standard)].try_into().unwrap(),
+		));
+
+		assert_eq!(
+			stable_balance(&Supersig::deposit_account()),
+			member_deposit(3) + escrow_floor()
+		);
+		assert_eq!(stable_balance(&supersig_account), 10_000 - member_deposit(1));
+		assert_eq!(Supersig::total_deposit(0), member_deposit(3));
+		assert_eq!(Balances::free_balance(&supersig_account), 0);
+	});
+}
+
+#[test]
+fn propose_call_escrows_deposit_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		let supersig_account = create_supersig(vec![ALICE(), BOB()]);
+		let native_balance = Balances::free_balance(BOB());
+		let bob_stable_balance = stable_balance(&BOB());
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 10] });
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.clone())
+		));
+
+		let deposit = StableBalance::from(call.encode().len() as u32)
+			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
+		assert_eq!(Supersig::calls(0, 0).unwrap().deposit, deposit);
+		assert_eq!(stable_balance(&BOB()), bob_stable_balance - deposit);
+		assert_eq!(
+			stable_balance(&Supersig::deposit_account()),
+			member_deposit(2) + deposit + escrow_floor()
+		);
+		assert_eq!(Balances::free_balance(BOB()), native_balance);
+
+		assert_ok!(Supersig::remove_call(RawOrigin::Signed(BOB()).into(), supersig_account, 0));
+		assert_eq!(stable_balance(&BOB()), bob_stable_balance);
+		assert_eq!(
+			stable_balance(&Supersig::deposit_account()),
+			member_deposit(2) + escrow_floor()
+		);
+	});
+}
+
+#[test]
+fn delete_supersig_sweeps_both_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		let supersig_account = create_supersig(vec![ALICE(), BOB()]);
+		let bob_stable_balance = stable_balance(&BOB());
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 10] });
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		assert_ok!(Assets::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			STABLECOIN.into(),
+			supersig_account.clone(),
+			10_000
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			20_000
+		));
+		let native_balance = Balances::free_balance(CHARLIE());
+		let charlie_stable_balance = stable_balance(&CHARLIE());
+
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			CHARLIE()
+		));
+
+		assert_eq!(stable_balance(&CHARLIE()), charlie_stable_balance + 10_000 + member_deposit(2));
+		assert_eq!(Balances::free_balance(CHARLIE()), native_balance + 20_000);
+		assert_eq!(stable_balance(&BOB()), bob_stable_balance);
+		assert_eq!(stable_balance(&Supersig::deposit_account()), escrow_floor());
+		assert_eq!(stable_balance(&supersig_account), 0);
+		assert_eq!(frame_system::Pallet::<Test>::providers(&supersig_account), 0);
+		assert!(!frame_system::Account::<Test>::contains_key(&supersig_account));
+	});
+}
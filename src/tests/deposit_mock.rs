@@ -0,0 +1,226 @@
+//! A runtime whose deposits are paid in a stablecoin of pallet-assets, with a balance type
+//! different from the native currency one.
+
+pub use super::mock::{ALICE, BOB, CHARLIE};
+use crate as pallet_supersig;
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Everything},
+	weights::Weight,
+	PalletId,
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{AccountIdConversion, BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	MultiSignature,
+};
+use xcm::latest::prelude::*;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+type AccountPublic = <MultiSignature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Supersig: pallet_supersig::{Pallet, Call, Storage, Event<T>, Origin},
+
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Nfts: pallet_nfts,
+	}
+);
+
+impl system::Config for Test {
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type AccountId = AccountId;
+	type BaseCallFilter = Everything;
+	type BlockHashCount = ConstU64<250>;
+	type BlockLength = ();
+	type BlockNumber = u64;
+	type BlockWeights = ();
+	type DbWeight = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type Index = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type MaxConsumers = ConstU32<16>;
+	type OnKilledAccount = ();
+	type OnNewAccount = ();
+	type OnSetCode = ();
+	type PalletInfo = PalletInfo;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SS58Prefix = ();
+	type SystemWeightInfo = ();
+	type Version = ();
+}
+
+pub type Balance = u64;
+pub type StableBalance = u128;
+
+/// The asset the deposits are paid in
+pub const STABLECOIN: u32 = 42;
+
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1_000>;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ConstU32<2>;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<10>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Balance = StableBalance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	type CreateOrigin = AsEnsureOriginWithArg<system::EnsureSigned<AccountId>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = system::EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type RemoveItemsLimit = ConstU32<5>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub NftsFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Test {
+	type ApprovalsLimit = ConstU32<10>;
+	type AttributeDepositBase = ConstU64<1>;
+	type CollectionDeposit = ConstU64<2>;
+	type CollectionId = u32;
+	type CreateOrigin = AsEnsureOriginWithArg<system::EnsureSigned<AccountId>>;
+	type Currency = Balances;
+	type DepositPerByte = ConstU64<1>;
+	type Features = NftsFeatures;
+	type ForceOrigin = system::EnsureRoot<AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type ItemDeposit = ConstU64<1>;
+	type ItemId = u32;
+	type KeyLimit = ConstU32<50>;
+	type Locker = ();
+	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxDeadlineDuration = ConstU64<10_000>;
+	type MaxTips = ConstU32<10>;
+	type MetadataDepositBase = ConstU64<1>;
+	type OffchainPublic = AccountPublic;
+	type OffchainSignature = MultiSignature;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const SupersigPalletId: PalletId = PalletId(*b"id/susig");
+	pub const StableByteDeposit: StableBalance = 10;
+	pub const MaxCallWeight: Weight = Weight::from_parts(1_000_000_000_000, 1024 * 1024);
+	pub const Stablecoin: u32 = STABLECOIN;
+	pub const SupersigNetwork: Option<NetworkId> = None;
+}
+
+impl pallet_supersig::Config for Test {
+	type Assets = Assets;
+	type Call = RuntimeCall;
+	type CollectionId = u32;
+	type Currency = Balances;
+	type DepositAssetId = Stablecoin;
+	type DepositAssets = Assets;
+	type DepositPerByte = StableByteDeposit;
+	type ItemId = u32;
+	type MaxAccountsPerTransaction = ConstU32<4>;
+	type MaxCallDataSize = ConstU32<1024>;
+	type MaxCallWeight = MaxCallWeight;
+	type MaxCallsPerAccount = ConstU32<3>;
+	type MaxNestingDepth = ConstU32<4>;
+	type MaxNestingVisits = ConstU32<8>;
+	type MaxOutflowBuckets = ConstU32<4>;
+	type MaxSnapshotSize = ConstU32<4>;
+	type MaxVaults = ConstU32<2>;
+	type Nfts = Nfts;
+	type PalletId = SupersigPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SupersigLocation = pallet_supersig::SupersigAsAccountId32<SupersigNetwork>;
+	type WeightInfo = pallet_supersig::weights::SubstrateWeight<Test>;
+	type XcmExecutor = ();
+	type XcmOrigin = system::EnsureNever<MultiLocation>;
+	type XcmRouter = ();
+}
+
+pub fn get_supersig_account(index: u64) -> AccountId {
+	SupersigPalletId::get().into_sub_account_truncating(index)
+}
+
+pub struct ExtBuilder {
+	native_balances: Vec<(AccountId, Balance)>,
+	stable_balances: Vec<(AccountId, StableBalance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder {
+			native_balances: vec![(ALICE(), 1_000_000), (BOB(), 100_000), (CHARLIE(), 100_000)],
+			stable_balances: vec![(ALICE(), 1_000_000), (BOB(), 100_000), (CHARLIE(), 100_000)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: self.native_balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		pallet_assets::GenesisConfig::<Test> {
+			assets: vec![(STABLECOIN, ALICE(), true, 100)],
+			metadata: vec![],
+			accounts: self
+				.stable_balances
+				.into_iter()
+				.map(|(who, balance)| (STABLECOIN, who, balance))
+				.collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,225 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+fn remark() -> RuntimeCall {
+	frame_system::Call::remark {
+		remark: "test".into(),
+	}
+	.into()
+}
+
+#[test]
+fn freeze() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_ok!(Supersig::freeze(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone()
+		));
+		assert_eq!(Supersig::frozen(0), Some(1));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::SupersigFrozen(
+				supersig_account.clone(),
+				ALICE()
+			))
+		);
+
+		assert_noop!(
+			Supersig::freeze(RawOrigin::Signed(ALICE()).into(), supersig_account.clone()),
+			Error::<Test>::SupersigFrozen
+		);
+		assert_noop!(
+			Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				Box::new(remark())
+			),
+			Error::<Test>::SupersigFrozen
+		);
+		assert_noop!(
+			Supersig::leave_supersig(RawOrigin::Signed(BOB()).into(), supersig_account.clone()),
+			Error::<Test>::SupersigFrozen
+		);
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account,
+				BOB(),
+				PAUL()
+			),
+			Error::<Test>::SupersigFrozen
+		);
+	})
+}
+
+#[test]
+fn freeze_not_allowed() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_noop!(
+			Supersig::freeze(RawOrigin::Signed(BOB()).into(), supersig_account.clone()),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Supersig::freeze(RawOrigin::Signed(PAUL()).into(), supersig_account),
+			Error::<Test>::NotAllowed
+		);
+	})
+}
+
+#[test]
+fn freeze_as_guardian() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_ok!(Supersig::set_guardians(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			vec![PAUL()].try_into().unwrap()
+		));
+		assert_eq!(Supersig::guardians(0).into_inner(), vec![PAUL()]);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::GuardiansSet(
+				supersig_account.clone(),
+				vec![PAUL()]
+			))
+		);
+
+		assert_ok!(Supersig::freeze(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account
+		));
+		assert!(Supersig::frozen(0).is_some());
+	})
+}
+
+#[test]
+fn frozen_supersig_does_not_execute_pending_calls() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(remark())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_ok!(Supersig::freeze(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone()
+		));
+
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(BOB()).into(), supersig_account, 0),
+			Error::<Test>::SupersigFrozen
+		);
+		assert!(Supersig::calls(0, 0).is_some());
+	})
+}
+
+#[test]
+fn unfreeze() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::freeze(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone()
+		));
+
+		let call: RuntimeCall = crate::Call::unfreeze {}.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert!(Supersig::frozen(0).is_some());
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_eq!(Supersig::frozen(0), None);
+		assert!(
+			frame_system::Pallet::<Test>::events().iter().any(|record| record.event
+				== RuntimeEvent::Supersig(crate::Event::SupersigUnfrozen(
+					supersig_account.clone()
+				)))
+		);
+
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account,
+			Box::new(remark())
+		));
+	})
+}
+
+#[test]
+fn unfreeze_not_frozen() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_noop!(
+			Supersig::unfreeze(RawOrigin::Signed(supersig_account).into()),
+			Error::<Test>::NotFrozen
+		);
+	})
+}
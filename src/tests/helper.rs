@@ -1,4 +1,7 @@
 use super::mock::*;
+use crate::Role;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
 use sp_runtime::traits::AccountIdConversion;
 
 pub fn get_supersig_account(index: u64) -> <Test as frame_system::Config>::AccountId {
@@ -8,3 +11,23 @@ pub fn get_supersig_account(index: u64) -> <Test as frame_system::Config>::Accou
 pub fn last_event() -> RuntimeEvent {
 	frame_system::Pallet::<Test>::events().pop().expect("Event expected").event
 }
+
+// Creates a supersig of `members` as ALICE, who then transfers `funds` to its account
+pub fn create_supersig(
+	members: Vec<(<Test as frame_system::Config>::AccountId, Role)>,
+	funds: Balance,
+) -> <Test as frame_system::Config>::AccountId {
+	assert_ok!(Supersig::create_supersig(
+		RawOrigin::Signed(ALICE()).into(),
+		members.try_into().unwrap()
+	));
+	let supersig_account = Supersig::supersig_account(Supersig::nonce_supersig() - 1);
+	if funds > 0 {
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			funds
+		));
+	}
+	supersig_account
+}
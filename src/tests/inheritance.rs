@@ -0,0 +1,241 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Inheritance, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+fn inheritance() -> Inheritance<sp_runtime::AccountId32, u64> {
+	Inheritance {
+		beneficiary: PAUL(),
+		inactivity_period: 10,
+		challenge_period: 5,
+	}
+}
+
+#[test]
+fn set_inheritance() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(inheritance())
+		));
+
+		assert_eq!(Supersig::inheritances(0), Some(inheritance()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::InheritanceSet(
+				supersig_account.clone(),
+				Some(inheritance())
+			))
+		);
+
+		assert_noop!(
+			Supersig::set_inheritance(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				Some(Inheritance {
+					inactivity_period: 0,
+					..inheritance()
+				})
+			),
+			Error::<Test>::InvalidPeriod
+		);
+
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account).into(),
+			None
+		));
+		assert_eq!(Supersig::inheritances(0), None);
+	})
+}
+
+#[test]
+fn claim_inheritance() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(inheritance())
+		));
+
+		assert_noop!(
+			Supersig::claim_inheritance(RawOrigin::Signed(PAUL()).into(), supersig_account.clone()),
+			Error::<Test>::SupersigActive
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Supersig::claim_inheritance(RawOrigin::Signed(BOB()).into(), supersig_account.clone()),
+			Error::<Test>::NoInheritance
+		);
+		assert_ok!(Supersig::claim_inheritance(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account.clone()
+		));
+		assert_eq!(Supersig::inheritance_claims(0), Some(10));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::InheritanceClaimed(
+				supersig_account.clone(),
+				PAUL()
+			))
+		);
+
+		assert_noop!(
+			Supersig::claim_inheritance(RawOrigin::Signed(PAUL()).into(), supersig_account),
+			Error::<Test>::ClaimPending
+		);
+	})
+}
+
+#[test]
+fn activity_delays_claim() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(inheritance())
+		));
+
+		System::set_block_number(5);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_eq!(Supersig::last_activity(0), 5);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Supersig::claim_inheritance(RawOrigin::Signed(PAUL()).into(), supersig_account.clone()),
+			Error::<Test>::SupersigActive
+		);
+
+		System::set_block_number(15);
+		assert_ok!(Supersig::claim_inheritance(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account
+		));
+	})
+}
+
+#[test]
+fn cancel_inheritance_claim() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(inheritance())
+		));
+
+		assert_noop!(
+			Supersig::cancel_inheritance_claim(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account.clone()
+			),
+			Error::<Test>::NoClaim
+		);
+
+		System::set_block_number(10);
+		assert_ok!(Supersig::claim_inheritance(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account.clone()
+		));
+		assert_noop!(
+			Supersig::cancel_inheritance_claim(
+				RawOrigin::Signed(CHARLIE()).into(),
+				supersig_account.clone()
+			),
+			Error::<Test>::NotMember
+		);
+		assert_ok!(Supersig::cancel_inheritance_claim(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone()
+		));
+		assert_eq!(Supersig::inheritance_claims(0), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::InheritanceClaimCancelled(
+				supersig_account.clone(),
+				BOB()
+			))
+		);
+
+		// cancelling counts as activity
+		assert_noop!(
+			Supersig::claim_inheritance(RawOrigin::Signed(PAUL()).into(), supersig_account),
+			Error::<Test>::SupersigActive
+		);
+	})
+}
+
+#[test]
+fn execute_inheritance() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_inheritance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(inheritance())
+		));
+		let paul_balance = Balances::free_balance(PAUL());
+		let supersig_balance = Balances::total_balance(&supersig_account);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Supersig::execute_inheritance(
+				RawOrigin::Signed(PAUL()).into(),
+				supersig_account.clone()
+			),
+			Error::<Test>::NoClaim
+		);
+		assert_ok!(Supersig::claim_inheritance(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account.clone()
+		));
+
+		System::set_block_number(14);
+		assert_noop!(
+			Supersig::execute_inheritance(
+				RawOrigin::Signed(PAUL()).into(),
+				supersig_account.clone()
+			),
+			Error::<Test>::ChallengePeriodNotOver
+		);
+
+		System::set_block_number(15);
+		assert_ok!(Supersig::execute_inheritance(
+			RawOrigin::Signed(PAUL()).into(),
+			supersig_account.clone()
+		));
+
+		assert_eq!(Supersig::total_members(0), 0);
+		assert_eq!(Supersig::inheritances(0), None);
+		assert_eq!(Supersig::inheritance_claims(0), None);
+		assert_eq!(
+			Balances::free_balance(PAUL()),
+			paul_balance + supersig_balance
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::InheritanceExecuted(supersig_account, PAUL()))
+		);
+	})
+}
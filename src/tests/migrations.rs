@@ -0,0 +1,235 @@
+use super::{helper::*, mock::*};
+use crate::{migrations, AccountSupersigs, Members, Role, TotalMasters};
+use frame_support::{
+	assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+};
+use frame_system::RawOrigin;
+
+#[test]
+fn migrate_to_v1_builds_account_supersigs() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		StorageVersion::new(0).put::<Supersig>();
+		for _ in 0..2 {
+			assert_ok!(Supersig::create_supersig(
+				RawOrigin::Signed(ALICE()).into(),
+				vec! {
+					(ALICE(), Role::Standard),
+					(BOB(), Role::Standard),
+				}
+				.try_into()
+				.unwrap()
+			));
+		}
+		// state before the index existed
+		let _ = AccountSupersigs::<Test>::clear(u32::MAX, None);
+		Members::<Test>::insert(2, CHARLIE(), Role::Master);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Supersig::on_chain_storage_version(), 1);
+		let mut alice_supersigs = Supersig::get_user_supersigs(&ALICE());
+		alice_supersigs.sort();
+		assert_eq!(alice_supersigs, vec![0, 1]);
+		assert_eq!(Supersig::get_user_supersigs(&CHARLIE()), vec![2]);
+		assert!(Supersig::get_user_supersigs(&PAUL()).is_empty());
+	})
+}
+
+#[test]
+fn migrate_to_v1_indexes_every_membership() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		StorageVersion::new(0).put::<Supersig>();
+		for supersig_id in 0..200 {
+			Members::<Test>::insert(supersig_id, CHARLIE(), Role::Standard);
+		}
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Supersig::on_chain_storage_version(), 1);
+		assert_eq!(Supersig::get_user_supersigs(&CHARLIE()).len(), 200);
+	})
+}
+
+// Moves the escrowed deposits back to where `ReservableCurrency` reserved them, with
+// `proposal_reserve` of the call deposit reserved
+fn unescrow_deposits(supersig_account: &sp_runtime::AccountId32, proposal_reserve: Balance) {
+	let member_deposit = Supersig::total_deposit(0);
+	let proposal_deposit = Supersig::calls(0, 0).unwrap().deposit;
+	assert_ok!(Balances::transfer(
+		RawOrigin::Signed(Supersig::deposit_account()).into(),
+		supersig_account.clone(),
+		member_deposit
+	));
+	assert_ok!(Balances::reserve(supersig_account, member_deposit));
+	// the reference that kept the supersig account alive
+	assert_ok!(System::inc_consumers(supersig_account));
+	assert_ok!(Balances::transfer(
+		RawOrigin::Signed(Supersig::deposit_account()).into(),
+		BOB(),
+		proposal_deposit
+	));
+	assert_ok!(Balances::reserve(&BOB(), proposal_reserve));
+}
+
+#[test]
+fn migrate_to_v2_converts_reserves_to_escrow() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		let member_deposit = Supersig::total_deposit(0);
+		let proposal_deposit = Supersig::calls(0, 0).unwrap().deposit;
+
+		// state before the deposits were escrowed
+		StorageVersion::new(1).put::<Supersig>();
+		unescrow_deposits(&supersig_account, proposal_deposit);
+		let bob_balance = Balances::free_balance(BOB());
+
+		migrations::v2::MigrateToV2::<Test, Balances>::on_runtime_upgrade();
+
+		assert_eq!(Supersig::on_chain_storage_version(), 2);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			member_deposit + proposal_deposit + ExistentialDeposit::get()
+		);
+		assert_eq!(Balances::reserved_balance(&supersig_account), 0);
+		assert_eq!(Balances::reserved_balance(BOB()), 0);
+		assert_eq!(Balances::free_balance(BOB()), bob_balance);
+
+		// the deposits are refunded as before
+		assert_ok!(Supersig::remove_call(RawOrigin::Signed(BOB()).into(), supersig_account, 0));
+		assert_eq!(Balances::free_balance(BOB()), bob_balance + proposal_deposit);
+	})
+}
+
+#[test]
+fn migrate_to_v2_records_escrowed_deposits() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		let member_deposit = Supersig::total_deposit(0);
+		let proposal_deposit = Supersig::calls(0, 0).unwrap().deposit;
+
+		// only half of the recorded call deposit is reserved
+		StorageVersion::new(1).put::<Supersig>();
+		unescrow_deposits(&supersig_account, proposal_deposit / 2);
+
+		migrations::v2::MigrateToV2::<Test, Balances>::on_runtime_upgrade();
+
+		assert_eq!(Supersig::total_deposit(0), member_deposit);
+		assert_eq!(Supersig::calls(0, 0).unwrap().deposit, proposal_deposit / 2);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			member_deposit + proposal_deposit / 2 + ExistentialDeposit::get()
+		);
+	})
+}
+
+#[test]
+fn account_supersigs_follows_membership() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			100_000
+		));
+		assert_eq!(Supersig::get_user_supersigs(&BOB()), vec![0]);
+
+		assert_ok!(Supersig::remove_members(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			vec!(BOB()).try_into().unwrap()
+		));
+		assert!(!AccountSupersigs::<Test>::contains_key(BOB(), 0));
+
+		assert_ok!(Supersig::leave_supersig(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone()
+		));
+		assert!(!AccountSupersigs::<Test>::contains_key(CHARLIE(), 0));
+
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			ALICE(),
+			PAUL()
+		));
+		assert!(Supersig::get_user_supersigs(&ALICE()).is_empty());
+		assert_eq!(Supersig::get_user_supersigs(&PAUL()), vec![0]);
+
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(supersig_account).into(),
+			ALICE()
+		));
+		assert!(!AccountSupersigs::<Test>::contains_key(PAUL(), 0));
+	})
+}
+
+#[test]
+fn migrate_to_v3_counts_masters() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Master),
+				(BOB(), Role::Master),
+				(CHARLIE(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		assert_eq!(Supersig::total_masters(0), 2);
+		StorageVersion::new(2).put::<Supersig>();
+		TotalMasters::<Test>::remove(0);
+
+		migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Supersig::on_chain_storage_version(), 3);
+		assert_eq!(Supersig::total_masters(0), 2);
+		assert_eq!(Supersig::total_role_weight(0), 3);
+	})
+}
@@ -1,5 +1,11 @@
+use super::xcm_mock::ParachainXcmRouter;
 use crate as pallet_supersig;
-use frame_support::{parameter_types, traits::Everything, PalletId};
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Everything, Nothing},
+	weights::Weight,
+	PalletId,
+};
 use frame_system as system;
 use sp_core::{sr25519, Pair, Public, H256};
 use sp_runtime::{
@@ -7,6 +13,13 @@ use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 	MultiSignature,
 };
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowUnpaidExecutionFrom, CurrencyAdapter, EnsureXcmOrigin,
+	FixedWeightBounds, IsConcrete, SignedToAccountId32, XcmPassthrough,
+};
+use xcm_executor::XcmExecutor;
+use xcm_simulator::ParaId;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -19,9 +32,12 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Supersig: pallet_supersig::{Pallet, Call, Storage, Event<T>},
+		Supersig: pallet_supersig::{Pallet, Call, Storage, Event<T>, Origin},
 
 		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Nfts: pallet_nfts,
+		PolkadotXcm: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin},
 	}
 );
 
@@ -70,10 +86,70 @@ impl pallet_balances::Config for Test {
 	type Balance = Balance;
 	type DustRemoval = ();
 	type ExistentialDeposit = ExistentialDeposit;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ConstU32<2>;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+	type ApprovalDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<10>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Balance = Balance;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type CallbackHandle = ();
+	type CreateOrigin = AsEnsureOriginWithArg<system::EnsureSigned<AccountId>>;
+	type Currency = Balances;
+	type Extra = ();
+	type ForceOrigin = system::EnsureRoot<AccountId>;
+	type Freezer = ();
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type RemoveItemsLimit = ConstU32<5>;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub NftsFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Test {
+	type ApprovalsLimit = ConstU32<10>;
+	type AttributeDepositBase = ConstU64<1>;
+	type CollectionDeposit = ConstU64<2>;
+	type CollectionId = u32;
+	type CreateOrigin = AsEnsureOriginWithArg<system::EnsureSigned<AccountId>>;
+	type Currency = Balances;
+	type DepositPerByte = ConstU64<1>;
+	type Features = NftsFeatures;
+	type ForceOrigin = system::EnsureRoot<AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type ItemDeposit = ConstU64<1>;
+	type ItemId = u32;
+	type KeyLimit = ConstU32<50>;
+	type Locker = ();
+	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxDeadlineDuration = ConstU64<10_000>;
+	type MaxTips = ConstU32<10>;
+	type MetadataDepositBase = ConstU64<1>;
+	type OffchainPublic = AccountPublic;
+	type OffchainSignature = MultiSignature;
+	type RuntimeEvent = RuntimeEvent;
+	type StringLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
 	type WeightInfo = ();
 }
 
@@ -82,20 +158,142 @@ parameter_types! {
 	pub const SupersigPreimageByteDeposit: Balance = 1000;
 	pub const MaxAccountsPerTransaction: u32 = 4;
 	pub const MaxCallDataSize: u32 = 1024;
+	pub const MaxCallWeight: Weight = Weight::from_parts(1_000_000_000_000, 1024 * 1024);
 	pub const MaxCallsPerAccount: u32 = 3;
+	pub const MaxNestingDepth: u32 = 4;
+	pub const MaxNestingVisits: u32 = 8;
+	pub const MaxVaults: u32 = 2;
+	pub const MaxSnapshotSize: u32 = 4;
+	pub const MaxOutflowBuckets: u32 = 2;
+	pub const LocalParaId: ParaId = ParaId::new(1);
+	pub const SupersigNetwork: Option<NetworkId> = None;
 
 }
 
 impl pallet_supersig::Config for Test {
+	type Assets = Assets;
 	type Call = RuntimeCall;
+	type CollectionId = u32;
 	type Currency = Balances;
+	type DepositAssetId = ();
+	type DepositAssets = pallet_supersig::FungibleAsAsset<Balances>;
 	type DepositPerByte = SupersigPreimageByteDeposit;
+	type ItemId = u32;
 	type MaxAccountsPerTransaction = MaxAccountsPerTransaction;
 	type MaxCallDataSize = MaxCallDataSize;
+	type MaxCallWeight = MaxCallWeight;
 	type MaxCallsPerAccount = MaxCallsPerAccount;
+	type MaxNestingDepth = MaxNestingDepth;
+	type MaxNestingVisits = MaxNestingVisits;
+	type MaxOutflowBuckets = MaxOutflowBuckets;
+	type MaxSnapshotSize = MaxSnapshotSize;
+	type MaxVaults = MaxVaults;
+	type Nfts = Nfts;
 	type PalletId = SupersigPalletId;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SupersigLocation = pallet_supersig::SupersigAsAccountId32<SupersigNetwork>;
 	type WeightInfo = pallet_supersig::weights::SubstrateWeight<Test>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmOrigin = pallet_xcm::EnsureXcm<Everything>;
+	type XcmRouter = PricedXcmRouter;
+}
+
+pub type XcmRouter = ParachainXcmRouter<LocalParaId>;
+
+/// `XcmRouter`, asking `DeliveryFee` of the native currency for each message
+pub struct PricedXcmRouter;
+impl SendXcm for PricedXcmRouter {
+	type Ticket = <XcmRouter as SendXcm>::Ticket;
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let (ticket, _) = XcmRouter::validate(dest, message)?;
+		Ok((ticket, (Here, DeliveryFee::get()).into()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		XcmRouter::deliver(ticket)
+	}
+}
+
+parameter_types! {
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(1));
+	pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	pub ReachableDest: Option<MultiLocation> = Some(Parent.into());
+	pub const NativeLocation: MultiLocation = MultiLocation::here();
+	pub const DeliveryFee: Balance = 1_000;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, SupersigNetwork>;
+
+pub type LocalAssetTransactor = CurrencyAdapter<
+	Balances,
+	IsConcrete<NativeLocation>,
+	AccountId32Aliases<SupersigNetwork, AccountId>,
+	AccountId,
+	(),
+>;
+
+/// The XCM messages sent to this chain can only dispatch calls with an `Xcm` origin, e.g. to
+/// vote with `approve_call_remote`. The native currency pays the delivery of the messages of the
+/// supersigs
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type AssetClaims = ();
+	type AssetExchanger = ();
+	type AssetLocker = ();
+	type AssetTransactor = LocalAssetTransactor;
+	type AssetTrap = ();
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type CallDispatcher = RuntimeCall;
+	type FeeManager = ();
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type MessageExporter = ();
+	type OriginConverter = XcmPassthrough<RuntimeOrigin>;
+	type PalletInstancesInfo = ();
+	type ResponseHandler = ();
+	type RuntimeCall = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type SubscriptionService = ();
+	type Trader = ();
+	type UniversalAliases = Nothing;
+	type UniversalLocation = UniversalLocation;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type XcmSender = XcmRouter;
+}
+
+impl pallet_xcm::Config for Test {
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type ReachableDest = ReachableDest;
+	type RemoteLockConsumerIdentifier = ();
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type SovereignAccountOf = AccountId32Aliases<SupersigNetwork, AccountId>;
+	type TrustedLockers = ();
+	type UniversalLocation = UniversalLocation;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmReserveTransferFilter = Everything;
+	type XcmRouter = XcmRouter;
+	type XcmTeleportFilter = Nothing;
 }
 
 type AccountPublic = <MultiSignature as Verify>::Signer;
@@ -1,12 +1,30 @@
 mod add_members;
+mod add_vault;
 mod approve_call;
+mod approve_call_as_child;
+mod approve_call_remote;
 mod create_supersig;
+mod create_supersig_from_collection;
+mod create_supersig_with_salt;
 mod delete_supersig;
+mod deposit_currency;
+mod freeze;
+mod inheritance;
 mod leave_supersig;
+mod migrations;
 mod remove_call;
 mod remove_members;
+mod rotate_member;
 mod rpc_calls;
+mod send_xcm;
+mod set_allowance;
+mod set_invariants;
+mod set_outflow_limit;
+mod set_voting_mode;
 mod propose_call;
+mod propose_call_with_origin;
 
+pub mod deposit_mock;
 pub mod helper;
 pub mod mock;
+pub mod xcm_mock;
@@ -40,7 +40,10 @@ fn propose_calls() {
 		));
 		let deposit = Balance::from(call.encode().len() as u32)
 			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
-		assert_eq!(Balances::reserved_balance(ALICE()), deposit);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			Supersig::total_deposit(0) + deposit + ExistentialDeposit::get()
+		);
 		assert_eq!(Supersig::nonce_call(0), 1);
 		assert_eq!(
 			last_event(),
@@ -134,6 +137,37 @@ fn propose_call_data_too_large() {
     });
 }
 
+#[test]
+fn propose_too_heavy_call() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+		// An approval is charged MaxCallWeight on top of its own weight
+		let call: RuntimeCall = crate::Call::approve_call {
+			supersig_account: supersig_account.clone(),
+			call_id: 0,
+		}
+		.into();
+
+		assert_noop!(
+			Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account,
+				Box::new(call)
+			),
+			Error::<Test>::CallTooHeavy
+		);
+	});
+}
+
 // Test that the max live proposal limit is working
 #[test]
 fn test_live_proposals_limit() {
@@ -0,0 +1,245 @@
+use super::{helper::*, mock::*};
+use crate::{
+	CallOrigin, EnsureSupersigApproval, EnsureSupersigApprovalOf, Error, Origin as SupersigOrigin,
+	Role,
+};
+use frame_support::{assert_noop, assert_ok, parameter_types, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::DispatchError;
+pub use sp_std::boxed::Box;
+
+parameter_types! {
+	pub const FirstSupersig: u128 = 0;
+}
+
+#[test]
+fn propose_call_with_origin() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+
+		let call: RuntimeCall = frame_system::Call::remark_with_event {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call_with_origin(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call),
+			CallOrigin::Approved,
+			None
+		));
+		assert_eq!(Supersig::call_origins(0, 0), CallOrigin::Approved);
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		// The call is not dispatched from the supersig account, so it can't be signed
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account,
+				0,
+				Err(DispatchError::BadOrigin.into())
+			))
+		);
+		assert_eq!(Supersig::call_origins(0, 0), CallOrigin::Signed);
+	})
+}
+
+#[test]
+fn propose_call_signed_by_default() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Master),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+
+		let call: RuntimeCall = frame_system::Call::remark_with_event {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call_with_origin(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call),
+			CallOrigin::Signed,
+			None
+		));
+		assert_eq!(Supersig::call_origins(0, 0), CallOrigin::Signed);
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account,
+				0,
+				Ok(().into())
+			))
+		);
+	})
+}
+
+#[test]
+fn propose_call_with_threshold() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+
+		let call: RuntimeCall = frame_system::Call::remark_with_event {
+			remark: "test".into(),
+		}
+		.into();
+		assert_noop!(
+			Supersig::propose_call_with_origin(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				Box::new(call.clone()),
+				CallOrigin::Signed,
+				Some(4)
+			),
+			Error::<Test>::ThresholdTooHigh
+		);
+		assert_ok!(Supersig::propose_call_with_origin(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call),
+			CallOrigin::Signed,
+			Some(3)
+		));
+		assert_eq!(Supersig::call_thresholds(0, 0), Some(3));
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+		// a simple majority is not enough
+		assert!(Supersig::calls(0, 0).is_some());
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert!(Supersig::calls(0, 0).is_none());
+		assert_eq!(Supersig::call_thresholds(0, 0), None);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account,
+				0,
+				Ok(().into())
+			))
+		);
+	})
+}
+
+#[test]
+fn approved_origin_total_is_sum_of_weights() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let supersig_account = get_supersig_account(0);
+
+		let call: RuntimeCall = frame_system::Call::remark_with_event {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call_with_origin(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call),
+			CallOrigin::Approved,
+			None
+		));
+
+		// the master weights 2, the standard members 1 each
+		let (origin, _) = Supersig::dispatch_origin(0, &supersig_account, 0, 2);
+		let origin: Result<SupersigOrigin, RuntimeOrigin> = origin.into();
+		assert_eq!(origin.ok(), Some(SupersigOrigin::Approved(0, 2, 5)));
+	})
+}
+
+#[test]
+fn ensure_supersig_approval() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let approved = |approvals, total| -> RuntimeOrigin {
+			SupersigOrigin::Approved(0, approvals, total).into()
+		};
+
+		assert_eq!(
+			EnsureSupersigApproval::<2, 3>::try_origin(approved(2, 3)).ok(),
+			Some(0)
+		);
+		assert_eq!(
+			EnsureSupersigApproval::<2, 3>::try_origin(approved(3, 4)).ok(),
+			Some(0)
+		);
+		assert!(EnsureSupersigApproval::<2, 3>::try_origin(approved(3, 5)).is_err());
+		assert!(
+			EnsureSupersigApproval::<2, 3>::try_origin(RuntimeOrigin::signed(ALICE())).is_err()
+		);
+		assert!(EnsureSupersigApproval::<2, 3>::try_origin(RuntimeOrigin::root()).is_err());
+
+		assert_eq!(
+			EnsureSupersigApprovalOf::<FirstSupersig, 1, 2>::try_origin(approved(2, 3)).ok(),
+			Some(0)
+		);
+		assert!(
+			EnsureSupersigApprovalOf::<FirstSupersig, 1, 2>::try_origin(
+				SupersigOrigin::Approved(1, 2, 3).into()
+			)
+			.is_err()
+		);
+	})
+}
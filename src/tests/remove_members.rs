@@ -32,7 +32,10 @@ fn remove_members() {
 		let reserve = Balance::from(size_of::<<Test as frame_system::Config>::AccountId>() as u32)
 			.saturating_mul((Supersig::total_members(0) as u32).into())
 			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
-		assert_eq!(Balances::reserved_balance(get_supersig_account(0)), reserve);
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			reserve + ExistentialDeposit::get()
+		);
 		assert_eq!(
 			last_event(),
 			RuntimeEvent::Supersig(crate::Event::MembersRemoved(
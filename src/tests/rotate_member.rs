@@ -0,0 +1,279 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+#[test]
+fn rotate_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+		let total_deposit = Supersig::total_deposit(0);
+
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			BOB(),
+			DONALD()
+		));
+
+		assert_eq!(Supersig::members(0, BOB()), Role::NotMember);
+		assert_eq!(Supersig::members(0, DONALD()), Role::Standard);
+		assert_eq!(Supersig::total_members(0), 4);
+		assert_eq!(Supersig::total_deposit(0), total_deposit);
+		assert!(!Supersig::members_votes((0, 0, BOB())));
+		assert!(Supersig::members_votes((0, 0, DONALD())));
+		assert_eq!(Supersig::votes(0, 0), 1);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::MemberRotated(supersig_account, BOB(), DONALD()))
+		);
+	})
+}
+
+#[test]
+fn rotate_member_keeps_role() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			supersig_account.clone(),
+			ALICE(),
+			DONALD()
+		));
+		assert_eq!(Supersig::members(0, ALICE()), Role::NotMember);
+		assert_eq!(Supersig::members(0, DONALD()), Role::Master);
+
+		// the new key approves with the Master weight of 2, which with BOB's vote reaches the
+		// threshold of 3 and executes the call
+		assert_eq!(Supersig::votes(0, 0), 1);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(DONALD()).into(),
+			supersig_account,
+			0
+		));
+		assert!(Supersig::calls(0, 0).is_none());
+	})
+}
+
+#[test]
+fn rotate_member_not_allowed() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(CHARLIE()).into(),
+				supersig_account,
+				BOB(),
+				DONALD()
+			),
+			Error::<Test>::NotAllowed
+		);
+	})
+}
+
+#[test]
+fn rotate_not_a_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				supersig_account,
+				DONALD(),
+				BOB()
+			),
+			Error::<Test>::NotMember
+		);
+	})
+}
+
+#[test]
+fn rotate_to_existing_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account,
+				BOB(),
+				CHARLIE()
+			),
+			Error::<Test>::AlreadyMember
+		);
+	})
+}
+
+#[test]
+fn rotate_member_with_pending_child_votes() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 is a member of supersig 1
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Standard),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		let parent_account = create_supersig(
+			vec![
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			],
+			0,
+		);
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call.into())
+		));
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account.clone(),
+			parent_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::pending_child_votes(0, ALICE()), 1);
+
+		assert_noop!(
+			Supersig::rotate_member(
+				RawOrigin::Signed(ALICE()).into(),
+				child_account.clone(),
+				ALICE(),
+				DONALD()
+			),
+			Error::<Test>::ChildVotesPending
+		);
+		// BOB didn't vote
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(BOB()).into(),
+			child_account.clone(),
+			BOB(),
+			PAUL()
+		));
+
+		// the call is gone, so is the vote of ALICE
+		assert_ok!(Supersig::remove_call(RawOrigin::Signed(PAUL()).into(), parent_account, 0));
+		assert!(!Supersig::child_members_votes((1, 0, 0, ALICE())));
+		assert_eq!(Supersig::pending_child_votes(0, ALICE()), 0);
+		assert_ok!(Supersig::rotate_member(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account,
+			ALICE(),
+			DONALD()
+		));
+	})
+}
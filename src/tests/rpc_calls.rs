@@ -1,52 +1,53 @@
 use super::{helper::*, mock::*};
-use crate::{rpc::ProposalState, Error, Role};
+use crate::{
+	rpc::{
+		CallClosure, CallDescription, ProposalState, ProposalStateV2, SupersigInfo,
+		MAX_SCANNED_IDS,
+	},
+	Config as SuperConfig, Error, NonceSupersig, Role,
+};
 use codec::Encode;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, traits::Get};
 use frame_system::RawOrigin;
+use sp_core::hashing::blake2_256;
 pub use sp_std::{boxed::Box, mem::size_of};
 
-fn create_supersig(supersig_id: u128) -> sp_runtime::AccountId32 {
-	let creator = vec![(ALICE(), Role::Master)].try_into().unwrap();
-	assert_ok!(Supersig::create_supersig(
-		RawOrigin::Signed(ALICE()).into(),
-		creator,
-	));
-	let supersig_account = get_supersig_account(u64::try_from(supersig_id).unwrap());
-	assert_ok!(Balances::transfer(
-		RawOrigin::Signed(ALICE()).into(),
-		supersig_account.clone(),
-		100_000
-	));
-	assert_ok!(Supersig::add_members(
-		RawOrigin::Signed(supersig_account.clone()).into(),
-		vec!((BOB(), Role::Standard), (CHARLIE(), Role::Standard)).try_into().unwrap()
-	));
-	assert_eq!(Supersig::members(supersig_id, ALICE()), Role::Master);
-	assert_eq!(Supersig::members(supersig_id, BOB()), Role::Standard);
-	assert_eq!(Supersig::members(supersig_id, CHARLIE()), Role::Standard);
-	assert_eq!(Supersig::total_members(supersig_id), 3);
-	supersig_account
-}
-
 #[test]
 fn get_account_supersigs() {
 	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
-		let mut supersig_count: u128 = 0;
-
 		assert_eq!(Supersig::get_user_supersigs(&ALICE()), vec![]);
 
-		create_supersig(supersig_count);
+		create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		let alice_supersigs = Supersig::get_user_supersigs(&ALICE());
 		assert!(alice_supersigs.contains(&0));
-		supersig_count += 1;
 
-		create_supersig(supersig_count);
+		create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		let alice_supersigs = Supersig::get_user_supersigs(&ALICE());
 		assert!(alice_supersigs.contains(&0));
 		assert!(alice_supersigs.contains(&1));
-		supersig_count += 1;
 
-		create_supersig(supersig_count);
+		create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		let alice_supersigs = Supersig::get_user_supersigs(&ALICE());
 		assert!(alice_supersigs.contains(&0));
 		assert!(alice_supersigs.contains(&1));
@@ -57,7 +58,14 @@ fn get_account_supersigs() {
 #[test]
 fn list_members() {
 	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
-		let supersig_account = create_supersig(0);
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		assert!(
 			Supersig::list_members(&supersig_account)
 				.unwrap()
@@ -79,7 +87,14 @@ fn list_members() {
 #[test]
 fn get_proposals() {
 	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
-		let supersig_account = create_supersig(0);
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		let call: RuntimeCall = frame_system::Call::remark {
 			remark: "test".into(),
 		}
@@ -131,10 +146,138 @@ fn get_proposals() {
 	})
 }
 
+#[test]
+fn get_closed_calls() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		for _ in 0..2 {
+			assert_ok!(Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				Box::new(call.clone())
+			));
+		}
+		assert_eq!(Supersig::get_closed_calls(&supersig_account), vec![]);
+
+		assert_ok!(Supersig::remove_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			1
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0,
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0,
+		));
+
+		assert_eq!(
+			Supersig::get_closed_calls(&supersig_account),
+			vec![(1, CallClosure::Removed), (0, CallClosure::Executed(Ok(())))]
+		);
+		assert_eq!(Supersig::get_closed_calls(&ALICE()), vec![]);
+	})
+}
+
+#[test]
+fn list_members_paged() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let members = Supersig::list_members(&supersig_account).unwrap();
+
+		let (first, next) = Supersig::list_members_paged(&supersig_account, None, 2).unwrap();
+		assert_eq!(first, members[..2].to_vec());
+		assert_eq!(next, Some(members[1].0.clone()));
+
+		let (second, next) = Supersig::list_members_paged(&supersig_account, next, 2).unwrap();
+		assert_eq!(second, members[2..].to_vec());
+		assert_eq!(next, None);
+
+		assert_eq!(
+			Supersig::list_members_paged(&supersig_account, None, 3),
+			Ok((members, None))
+		);
+		assert_noop!(
+			Supersig::list_members_paged(&ALICE(), None, 3),
+			Error::<Test>::NotSupersig
+		);
+	})
+}
+
+#[test]
+fn list_proposals_paged() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		for _ in 0..3 {
+			assert_ok!(Supersig::propose_call(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account.clone(),
+				Box::new(call.clone())
+			));
+		}
+		let (proposals, member_count) = Supersig::list_proposals(&supersig_account).unwrap();
+
+		let mut start = None;
+		let mut pages = vec![];
+		loop {
+			let (page, count, next) =
+				Supersig::list_proposals_paged(&supersig_account, start, 1).unwrap();
+			assert_eq!(count, member_count);
+			assert_eq!(page.len(), 1);
+			pages.extend(page);
+			if next.is_none() {
+				break
+			}
+			start = next;
+		}
+		assert_eq!(pages, proposals);
+	})
+}
+
 #[test]
 fn get_proposal_state() {
 	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
-		let supersig_account = create_supersig(0);
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
 		let call: RuntimeCall = frame_system::Call::remark {
 			remark: "test".into(),
 		}
@@ -182,3 +325,520 @@ fn get_proposal_state() {
 		);
 	})
 }
+
+#[test]
+fn get_supersig_tree() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(child_account.clone(), Role::Master), (PAUL(), Role::Standard)]
+				.try_into()
+				.unwrap(),
+		));
+		let parent_account = get_supersig_account(1);
+
+		let tree = Supersig::get_supersig_tree(&parent_account).unwrap();
+		assert_eq!(tree.account(), &parent_account);
+		assert_eq!(tree.members().len(), 2);
+		assert!(tree.members().contains(&(PAUL(), Role::Standard, None)));
+
+		let child_tree = Supersig::get_supersig_tree(&child_account).unwrap();
+		assert_eq!(child_tree.members().len(), 3);
+		assert!(tree.members().contains(&(child_account, Role::Master, Some(child_tree))));
+	})
+}
+
+#[test]
+fn get_proposal_state_v2() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::add_members(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			vec!((PAUL(), Role::Standard), (DONALD(), Role::Standard)).try_into().unwrap()
+		));
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+
+		assert_noop!(
+			Supersig::get_proposal_state_v2(&supersig_account, &0),
+			Error::<Test>::CallNotFound
+		);
+
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(call.clone())
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0,
+		));
+
+		// with 5 members, the Master weights 2 and the threshold is 3
+		let deposit = Supersig::calls(0, 0).unwrap().deposit;
+		let expected = ProposalStateV2::new(
+			0,
+			call.encode(),
+			blake2_256(&call.encode()),
+			call.get_dispatch_info().weight,
+			BOB(),
+			deposit,
+			vec![(ALICE(), 2)],
+			2,
+			3,
+		);
+		assert_ok!(Supersig::get_proposal_state_v2(&supersig_account, &0), expected.clone());
+		assert_ok!(Supersig::list_proposals_v2(&supersig_account), vec![expected]);
+	})
+}
+
+#[test]
+fn get_call_description() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+
+		assert_noop!(
+			Supersig::get_call_description(&supersig_account, &0),
+			Error::<Test>::CallNotFound
+		);
+
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call.clone())
+		));
+
+		let info = call.get_dispatch_info();
+		assert_ok!(
+			Supersig::get_call_description(&supersig_account, &0),
+			CallDescription::new(
+				call.encode(),
+				b"System".to_vec(),
+				b"remark".to_vec(),
+				info.weight,
+				info.class
+			)
+		);
+	})
+}
+
+#[test]
+fn dry_run_call() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let paul_balance = Balances::free_balance(PAUL());
+		let transfer = |value| -> RuntimeCall {
+			pallet_balances::Call::transfer {
+				dest: PAUL(),
+				value,
+			}
+			.into()
+		};
+
+		assert_noop!(
+			Supersig::dry_run_call(&supersig_account, &0),
+			Error::<Test>::CallNotFound
+		);
+
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(transfer(10_000))
+		));
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			Box::new(transfer(1_000_000_000))
+		));
+
+		let dry_run = Supersig::dry_run_call(&supersig_account, &0).unwrap();
+		assert!(dry_run.result().is_ok());
+		assert_eq!(
+			*dry_run.actual_weight(),
+			transfer(10_000).get_dispatch_info().weight
+		);
+		assert!(dry_run.events().contains(
+			&RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+				from: supersig_account.clone(),
+				to: PAUL(),
+				amount: 10_000,
+			})
+			.encode()
+		));
+
+		// nothing was applied
+		assert_eq!(Balances::free_balance(PAUL()), paul_balance);
+		assert!(Supersig::calls(0, 0).is_some());
+
+		let dry_run = Supersig::dry_run_call(&supersig_account, &1).unwrap();
+		assert!(dry_run.result().is_err());
+		assert!(Supersig::calls(0, 1).is_some());
+	})
+}
+
+#[test]
+fn deposit_quotes() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let quote = Supersig::quote_create_supersig(vec![ALICE(), BOB(), BOB()]).unwrap();
+		let deposit = Balance::from(size_of::<<Test as frame_system::Config>::AccountId>() as u32)
+			.saturating_mul(2)
+			.saturating_mul(<Test as SuperConfig>::DepositPerByte::get());
+		assert_eq!(*quote.deposit(), deposit);
+		// the first deposit also pays the existential deposit of the escrow
+		assert_eq!(*quote.transfer(), deposit + ExistentialDeposit::get());
+		// the existential deposit is transferred when it is higher than the deposit
+		let empty_quote = Supersig::quote_create_supersig(vec![]).unwrap();
+		assert_eq!(*empty_quote.deposit(), 0);
+		assert_eq!(*empty_quote.transfer(), 2 * ExistentialDeposit::get());
+
+		let alice_balance = Balances::free_balance(ALICE());
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec![(ALICE(), Role::Master), (BOB(), Role::Standard)].try_into().unwrap(),
+		));
+		let supersig_account = get_supersig_account(0);
+		assert_eq!(Balances::free_balance(ALICE()), alice_balance - quote.transfer());
+		assert_eq!(
+			Balances::free_balance(Supersig::deposit_account()),
+			quote.deposit() + ExistentialDeposit::get()
+		);
+
+		assert_eq!(
+			Supersig::quote_add_members(&supersig_account, vec![BOB(), CHARLIE(), CHARLIE()]),
+			Ok(deposit / 2)
+		);
+
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		let quote = Supersig::quote_propose_call(call.encode()).unwrap();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account,
+			Box::new(call)
+		));
+		assert_eq!(Supersig::calls(0, 0).unwrap().deposit, quote);
+
+		assert_noop!(
+			Supersig::quote_propose_call(vec![0; 1025]),
+			Error::<Test>::CallDataTooLarge
+		);
+	})
+}
+
+#[test]
+fn get_pending_for_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let first_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let second_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		for supersig_account in [&first_supersig, &second_supersig] {
+			assert_ok!(Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				Box::new(call.clone())
+			));
+		}
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			first_supersig.clone(),
+			0,
+		));
+
+		let pending = Supersig::get_pending_for_member(&BOB());
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].0, second_supersig);
+		assert_eq!(*pending[0].1.id(), 0);
+		assert_eq!(pending[0].1.tally(), 0);
+		assert_eq!(pending[0].1.threshold(), 2);
+
+		let pending = Supersig::get_pending_for_member(&ALICE());
+		assert_eq!(pending.len(), 2);
+		assert!(pending.iter().any(|(supersig_account, proposal)| *supersig_account ==
+			first_supersig && proposal.tally() == 1));
+
+		assert_eq!(Supersig::get_pending_for_member(&PAUL()), vec![]);
+	})
+}
+
+#[test]
+fn get_pending_for_collection_holder() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			ALICE(),
+			pallet_nfts::CollectionConfig {
+				settings: pallet_nfts::CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: pallet_nfts::MintSettings::default(),
+			}
+		));
+		for (item, owner) in [(0, BOB()), (1, CHARLIE()), (2, CHARLIE())] {
+			assert_ok!(Nfts::mint(RawOrigin::Signed(ALICE()).into(), 0, item, owner, None));
+		}
+		assert_ok!(Supersig::create_supersig_from_collection(RawOrigin::Signed(ALICE()).into(), 0));
+		let supersig_account = get_supersig_account(0);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+
+		let pending = Supersig::get_pending_for_member(&BOB());
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].0, supersig_account);
+		assert_eq!(*pending[0].1.id(), 0);
+
+		assert_ok!(Supersig::approve_call(RawOrigin::Signed(BOB()).into(), supersig_account, 0));
+		assert_eq!(Supersig::get_pending_for_member(&BOB()), vec![]);
+		assert_eq!(Supersig::get_pending_for_member(&CHARLIE()).len(), 1);
+		assert_eq!(Supersig::get_pending_for_member(&ALICE()), vec![]);
+	})
+}
+
+#[test]
+fn get_pending_for_child_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// supersig 0 is a member of supersig 1
+		let child_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(child_account.clone(), Role::Standard),
+				(PAUL(), Role::Standard),
+			}
+			.try_into()
+			.unwrap()
+		));
+		let parent_account = get_supersig_account(1);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(PAUL()).into(),
+			parent_account.clone(),
+			Box::new(call)
+		));
+
+		let pending = Supersig::get_pending_for_member(&ALICE());
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].0, parent_account);
+		assert_eq!(*pending[0].1.id(), 0);
+
+		assert_ok!(Supersig::approve_call_as_child(
+			RawOrigin::Signed(ALICE()).into(),
+			child_account,
+			parent_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::get_pending_for_member(&ALICE()), vec![]);
+		assert_eq!(Supersig::get_pending_for_member(&BOB()).len(), 1);
+		assert_eq!(Supersig::get_pending_for_member(&PAUL()).len(), 1);
+	})
+}
+
+#[test]
+fn get_supersig_info() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let call: RuntimeCall = frame_system::Call::remark {
+			remark: "test".into(),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+
+		assert_ok!(
+			Supersig::get_supersig_info(&supersig_account),
+			SupersigInfo::new(
+				supersig_account.clone(),
+				0,
+				3,
+				Balances::free_balance(&supersig_account),
+				0,
+				Supersig::total_deposit(0),
+				1,
+				1
+			)
+		);
+		assert_noop!(Supersig::get_supersig_info(&ALICE()), Error::<Test>::NotSupersig);
+	})
+}
+
+#[test]
+fn get_user_supersigs_paged() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		for _ in 0..3 {
+			create_supersig(
+				vec![
+					(ALICE(), Role::Master),
+					(BOB(), Role::Standard),
+					(CHARLIE(), Role::Standard),
+				],
+				100_000,
+			);
+		}
+
+		let (first_page, next) = Supersig::get_user_supersigs_paged(&ALICE(), None, 2);
+		assert_eq!(first_page.len(), 2);
+		assert!(next.is_some());
+		let (last_page, next) = Supersig::get_user_supersigs_paged(&ALICE(), next, 2);
+		assert_eq!(last_page.len(), 1);
+		assert_eq!(next, None);
+
+		let mut supersigs = [first_page, last_page].concat();
+		supersigs.sort();
+		assert_eq!(supersigs, vec![0, 1, 2]);
+		assert_eq!(Supersig::get_user_supersigs_paged(&PAUL(), None, 2), (vec![], None));
+	})
+}
+
+#[test]
+fn list_supersigs() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let first_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let deleted_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		let last_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::delete_supersig(
+			RawOrigin::Signed(deleted_supersig.clone()).into(),
+			PAUL()
+		));
+
+		let (page, next) = Supersig::list_supersigs(None, 1);
+		assert_eq!(page, vec![(0, first_supersig.clone())]);
+		assert_eq!(next, Some(0));
+		let (page, next) = Supersig::list_supersigs(next, 1);
+		assert_eq!(page, vec![(2, last_supersig.clone())]);
+		assert_eq!(next, None);
+
+		assert_eq!(Supersig::get_supersig_account_by_id(0), Some(first_supersig));
+		assert_eq!(Supersig::get_supersig_account_by_id(1), None);
+		assert_eq!(Supersig::get_supersig_account_by_id(3), None);
+		assert_eq!(Supersig::get_supersig_id(&last_supersig), Some(2));
+		assert_eq!(Supersig::get_supersig_id(&deleted_supersig), None);
+		assert_eq!(Supersig::get_supersig_id(&ALICE()), None);
+	})
+}
+
+#[test]
+fn list_supersigs_reads_a_bounded_number_of_ids() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let first_supersig = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		// the ids up to 2 500 were taken by supersigs since deleted
+		NonceSupersig::<Test>::put(2_500);
+
+		let (page, next) = Supersig::list_supersigs(None, 10);
+		assert_eq!(page, vec![(0, first_supersig)]);
+		assert_eq!(next, Some(u128::from(MAX_SCANNED_IDS) - 1));
+		let (page, next) = Supersig::list_supersigs(next, 10);
+		assert_eq!(page, vec![]);
+		assert_eq!(next, Some(2 * u128::from(MAX_SCANNED_IDS) - 1));
+		let (page, next) = Supersig::list_supersigs(next, 10);
+		assert_eq!(page, vec![]);
+		assert_eq!(next, None);
+	})
+}
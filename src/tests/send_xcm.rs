@@ -0,0 +1,150 @@
+use super::{
+	helper::*,
+	mock::*,
+	xcm_mock::{remote, MockNet, ParaA, ParaB},
+};
+use crate::{Error, Role};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+use xcm::{latest::prelude::*, VersionedMultiLocation, VersionedXcm};
+use xcm_executor::traits::Convert;
+use xcm_simulator::TestExt;
+
+// The account the supersig of `ParaA` controls on `ParaB`
+fn remote_supersig_account() -> remote::AccountId {
+	let location = MultiLocation::new(
+		1,
+		X2(
+			Parachain(1),
+			AccountId32 {
+				network: None,
+				id: get_supersig_account(0).into(),
+			},
+		),
+	);
+	remote::LocationToAccountId::convert(location).unwrap()
+}
+
+fn transfer_to_bob(amount: u64) -> Box<VersionedXcm<()>> {
+	Box::new(VersionedXcm::from(Xcm(vec![
+		WithdrawAsset((Here, amount).into()),
+		DepositAsset {
+			assets: AllCounted(1).into(),
+			beneficiary: AccountId32 {
+				network: None,
+				id: BOB().into(),
+			}
+			.into(),
+		},
+	])))
+}
+
+fn remote_chain() -> Box<VersionedMultiLocation> {
+	Box::new(MultiLocation::new(1, X1(Parachain(2))).into())
+}
+
+#[test]
+fn send_xcm() {
+	MockNet::reset();
+
+	ParaB::execute_with(|| {
+		assert_ok!(remote::Balances::mint_into(
+			&remote_supersig_account(),
+			100_000
+		));
+	});
+
+	ParaA::execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 10_000);
+
+		let call: RuntimeCall = crate::Call::send_xcm {
+			dest: remote_chain(),
+			message: transfer_to_bob(40_000),
+		}
+		.into();
+		assert_ok!(Supersig::propose_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			Box::new(call)
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account.clone(),
+				0,
+				Ok(().into())
+			))
+		);
+		assert_eq!(Balances::free_balance(supersig_account), 10_000 - DeliveryFee::get());
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(remote::Balances::free_balance(remote_supersig_account()), 60_000);
+		assert_eq!(remote::Balances::free_balance(BOB()), 40_000);
+	});
+}
+
+#[test]
+fn send_xcm_not_supersig() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_noop!(
+			Supersig::send_xcm(
+				RawOrigin::Signed(ALICE()).into(),
+				remote_chain(),
+				transfer_to_bob(40_000)
+			),
+			Error::<Test>::NotSupersig
+		);
+	});
+}
+
+#[test]
+fn send_xcm_unreachable_destination() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		assert_ok!(Supersig::create_supersig(
+			RawOrigin::Signed(ALICE()).into(),
+			vec! {
+				(ALICE(), Role::Master),
+			}
+			.try_into()
+			.unwrap()
+		));
+
+		assert_noop!(
+			Supersig::send_xcm(
+				RawOrigin::Signed(get_supersig_account(0)).into(),
+				Box::new(MultiLocation::new(2, X1(GlobalConsensus(Kusama))).into()),
+				transfer_to_bob(40_000)
+			),
+			Error::<Test>::XcmSendFailed
+		);
+	});
+}
+
+#[test]
+fn send_xcm_fees_not_met() {
+	MockNet::reset();
+
+	ParaA::execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Master)], 0);
+
+		assert_noop!(
+			Supersig::send_xcm(
+				RawOrigin::Signed(supersig_account).into(),
+				remote_chain(),
+				transfer_to_bob(40_000)
+			),
+			Error::<Test>::FeesNotMet
+		);
+	});
+}
@@ -0,0 +1,170 @@
+use super::{helper::*, mock::*};
+use crate::{Allowance, Error, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+
+#[test]
+fn set_allowance() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			BOB(),
+			100,
+			10
+		));
+
+		assert_eq!(
+			Supersig::allowances(0, BOB()),
+			Some(Allowance {
+				amount: 100,
+				period: 10,
+				remaining: 100,
+				period_start: 1,
+			})
+		);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::AllowanceSet(supersig_account.clone(), BOB(), 100, 10))
+		);
+
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account).into(),
+			BOB(),
+			0,
+			0
+		));
+		assert_eq!(Supersig::allowances(0, BOB()), None);
+	})
+}
+
+#[test]
+fn set_allowance_errors() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			BOB(),
+			100,
+			10
+		));
+
+		assert_noop!(
+			Supersig::set_allowance(RawOrigin::Signed(ALICE()).into(), BOB(), 100, 10),
+			Error::<Test>::NotSupersig
+		);
+		assert_noop!(
+			Supersig::set_allowance(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				CHARLIE(),
+				100,
+				10
+			),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			Supersig::set_allowance(RawOrigin::Signed(supersig_account).into(), BOB(), 100, 0),
+			Error::<Test>::InvalidPeriod
+		);
+	})
+}
+
+#[test]
+fn spend_allowance() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			BOB(),
+			100,
+			10
+		));
+		let paul_balance = Balances::free_balance(PAUL());
+
+		assert_ok!(Supersig::spend_allowance(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			PAUL(),
+			60
+		));
+		assert_eq!(Balances::free_balance(PAUL()), paul_balance + 60);
+		assert_eq!(Supersig::allowances(0, BOB()).unwrap().remaining, 40);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::AllowanceSpent(
+				supersig_account.clone(),
+				BOB(),
+				PAUL(),
+				60
+			))
+		);
+
+		assert_noop!(
+			Supersig::spend_allowance(
+				RawOrigin::Signed(BOB()).into(),
+				supersig_account.clone(),
+				PAUL(),
+				41
+			),
+			Error::<Test>::AllowanceExceeded
+		);
+		assert_noop!(
+			Supersig::spend_allowance(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				PAUL(),
+				1
+			),
+			Error::<Test>::NoAllowance
+		);
+
+		// a new period starts with the full amount
+		System::set_block_number(11);
+		assert_eq!(
+			Supersig::get_allowance(&supersig_account, &BOB()).unwrap().unwrap().remaining,
+			100
+		);
+		assert_ok!(Supersig::spend_allowance(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account,
+			PAUL(),
+			100
+		));
+		assert_eq!(Supersig::allowances(0, BOB()).unwrap().period_start, 11);
+	})
+}
+
+#[test]
+fn allowance_removed_with_member() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![(ALICE(), Role::Standard), (BOB(), Role::Standard)],
+			100_000,
+		);
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			BOB(),
+			100,
+			10
+		));
+
+		assert_ok!(Supersig::leave_supersig(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone()
+		));
+		assert_eq!(Supersig::allowances(0, BOB()), None);
+		assert_noop!(
+			Supersig::spend_allowance(RawOrigin::Signed(BOB()).into(), supersig_account, PAUL(), 1),
+			Error::<Test>::NotMember
+		);
+	})
+}
@@ -0,0 +1,147 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Invariants, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+
+#[test]
+fn set_invariants() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let invariants = Invariants {
+			min_masters: 1,
+			min_total_weight: 3,
+		};
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::set_invariants(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			invariants.clone()
+		));
+
+		assert_eq!(Supersig::invariants(0), invariants);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::InvariantsSet(supersig_account, invariants))
+		);
+	})
+}
+
+#[test]
+fn set_invariants_already_violated() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_noop!(
+			Supersig::set_invariants(
+				RawOrigin::Signed(supersig_account).into(),
+				Invariants {
+					min_masters: 2,
+					min_total_weight: 0,
+				}
+			),
+			Error::<Test>::InvariantsViolated
+		);
+	})
+}
+
+#[test]
+fn set_invariants_not_supersig() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+
+		assert_noop!(
+			Supersig::set_invariants(RawOrigin::Signed(ALICE()).into(), Invariants::default()),
+			Error::<Test>::NotSupersig
+		);
+	})
+}
+
+#[test]
+fn remove_last_master_violates_invariants() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::set_invariants(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Invariants {
+				min_masters: 1,
+				min_total_weight: 0,
+			}
+		));
+
+		assert_noop!(
+			Supersig::remove_members(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				vec!(ALICE()).try_into().unwrap()
+			),
+			Error::<Test>::InvariantsViolated
+		);
+		assert_noop!(
+			Supersig::leave_supersig(RawOrigin::Signed(ALICE()).into(), supersig_account.clone()),
+			Error::<Test>::InvariantsViolated
+		);
+		assert_noop!(
+			Supersig::add_members(
+				RawOrigin::Signed(supersig_account.clone()).into(),
+				vec!((ALICE(), Role::Standard)).try_into().unwrap()
+			),
+			Error::<Test>::InvariantsViolated
+		);
+		assert_ok!(Supersig::leave_supersig(RawOrigin::Signed(BOB()).into(), supersig_account));
+	})
+}
+
+#[test]
+fn remove_members_below_min_total_weight() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		// ALICE weights 1 as a Master of a 3 members supersig, BOB and CHARLIE 1 each
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			100_000,
+		);
+		assert_ok!(Supersig::set_invariants(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Invariants {
+				min_masters: 0,
+				min_total_weight: 3,
+			}
+		));
+
+		assert_noop!(
+			Supersig::remove_members(
+				RawOrigin::Signed(supersig_account).into(),
+				vec!(BOB()).try_into().unwrap()
+			),
+			Error::<Test>::InvariantsViolated
+		);
+	})
+}
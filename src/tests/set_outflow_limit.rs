@@ -0,0 +1,285 @@
+use super::{helper::*, mock::*};
+use crate::{Error, OutflowLimit, Role};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+fn propose_transfer(supersig_account: &sp_runtime::AccountId32, value: u64) {
+	let call = pallet_balances::Call::transfer {
+		dest: BOB(),
+		value,
+	};
+	assert_ok!(Supersig::propose_call(
+		RawOrigin::Signed(ALICE()).into(),
+		supersig_account.clone(),
+		Box::new(call.into())
+	));
+}
+
+#[test]
+fn set_outflow_limit() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+		let limit = OutflowLimit {
+			max_amount: 1_000,
+			window: 10,
+		};
+
+		assert_eq!(Supersig::outflow_limits(0), Some(limit.clone()));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::OutflowLimitSet(
+				supersig_account.clone(),
+				Some(limit)
+			))
+		);
+
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account).into(),
+			None
+		));
+		assert_eq!(Supersig::outflow_limits(0), None);
+	})
+}
+
+#[test]
+fn set_outflow_limit_zero_window() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+
+		assert_noop!(
+			Supersig::set_outflow_limit(
+				RawOrigin::Signed(supersig_account).into(),
+				Some(OutflowLimit {
+					max_amount: 1_000,
+					window: 0,
+				})
+			),
+			Error::<Test>::InvalidPeriod
+		);
+	})
+}
+
+#[test]
+fn set_outflow_limit_not_supersig() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+
+		assert_noop!(
+			Supersig::set_outflow_limit(RawOrigin::Signed(ALICE()).into(), None),
+			Error::<Test>::NotSupersig
+		);
+	})
+}
+
+#[test]
+fn outflow_within_limit() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+		let bob_balance = Balances::free_balance(BOB());
+
+		propose_transfer(&supersig_account, 600);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		propose_transfer(&supersig_account, 400);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account,
+			1
+		));
+
+		assert_eq!(Balances::free_balance(BOB()), bob_balance + 1_000);
+		assert_eq!(Supersig::current_outflow(0), 1_000);
+	})
+}
+
+#[test]
+fn outflow_limit_exceeded_keeps_call() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+		let bob_balance = Balances::free_balance(BOB());
+
+		propose_transfer(&supersig_account, 600);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		propose_transfer(&supersig_account, 600);
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(ALICE()).into(), supersig_account.clone(), 1),
+			Error::<Test>::OutflowLimitExceeded
+		);
+		assert!(Supersig::calls(0, 1).is_some());
+		assert_eq!(Balances::free_balance(BOB()), bob_balance + 600);
+
+		// the outflow of block 1 leaves the window at block 11
+		System::set_block_number(11);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account,
+			1
+		));
+		assert!(Supersig::calls(0, 1).is_none());
+		assert_eq!(Balances::free_balance(BOB()), bob_balance + 1_200);
+		assert_eq!(Supersig::current_outflow(0), 600);
+	})
+}
+
+#[test]
+fn spend_allowance_counts_towards_limit() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			ALICE(),
+			2_000,
+			10
+		));
+
+		assert_ok!(Supersig::spend_allowance(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			BOB(),
+			800
+		));
+		assert_noop!(
+			Supersig::spend_allowance(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				BOB(),
+				300
+			),
+			Error::<Test>::OutflowLimitExceeded
+		);
+		assert_eq!(Supersig::current_outflow(0), 800);
+	})
+}
+
+#[test]
+fn outflow_window_slides() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+
+		propose_transfer(&supersig_account, 600);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		System::set_block_number(6);
+		propose_transfer(&supersig_account, 400);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			1
+		));
+		assert_eq!(Supersig::current_outflow(0), 1_000);
+
+		// only the outflow of block 1 left the window
+		System::set_block_number(11);
+		assert_eq!(Supersig::current_outflow(0), 400);
+		propose_transfer(&supersig_account, 700);
+		assert_noop!(
+			Supersig::approve_call(RawOrigin::Signed(ALICE()).into(), supersig_account.clone(), 2),
+			Error::<Test>::OutflowLimitExceeded
+		);
+		propose_transfer(&supersig_account, 600);
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account,
+			3
+		));
+		assert_eq!(Supersig::current_outflow(0), 1_000);
+	})
+}
+
+#[test]
+fn oldest_outflows_are_merged() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		let supersig_account = create_supersig(vec![(ALICE(), Role::Standard)], 100_000);
+		assert_ok!(Supersig::set_outflow_limit(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			Some(OutflowLimit {
+				max_amount: 1_000,
+				window: 10,
+			})
+		));
+		assert_ok!(Supersig::set_allowance(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			ALICE(),
+			2_000,
+			100
+		));
+
+		// MaxOutflowBuckets is 2
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(Supersig::spend_allowance(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account.clone(),
+				BOB(),
+				100
+			));
+		}
+		assert_eq!(Supersig::outflows(0).into_inner(), vec![(2, 200), (3, 100)]);
+
+		// the outflow of block 1 was merged into the one of block 2, it leaves the window later
+		System::set_block_number(11);
+		assert_eq!(Supersig::current_outflow(0), 300);
+		System::set_block_number(12);
+		assert_eq!(Supersig::current_outflow(0), 100);
+	})
+}
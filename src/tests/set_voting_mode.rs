@@ -0,0 +1,246 @@
+use super::{helper::*, mock::*};
+use crate::{Error, Role, VotingMode};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+pub use sp_std::boxed::Box;
+
+const ASSET: u32 = 0;
+
+// ALICE holds 20%, BOB 50% and CHARLIE 30% of the asset held by the members
+fn mint_asset() {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), ASSET.into(), ALICE(), true, 1));
+	assert_ok!(Assets::mint(RawOrigin::Signed(ALICE()).into(), ASSET.into(), ALICE(), 200));
+	assert_ok!(Assets::mint(RawOrigin::Signed(ALICE()).into(), ASSET.into(), BOB(), 500));
+	assert_ok!(Assets::mint(RawOrigin::Signed(ALICE()).into(), ASSET.into(), CHARLIE(), 300));
+}
+
+fn propose_remark(supersig_account: &sp_runtime::AccountId32) {
+	let call = frame_system::Call::remark {
+		remark: "test".into(),
+	};
+	assert_ok!(Supersig::propose_call(
+		RawOrigin::Signed(ALICE()).into(),
+		supersig_account.clone(),
+		Box::new(call.into())
+	));
+}
+
+#[test]
+fn set_voting_mode() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+
+		assert_eq!(Supersig::voting_mode(0), VotingMode::Token(ASSET));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::VotingModeSet(
+				supersig_account,
+				VotingMode::Token(ASSET)
+			))
+		);
+	})
+}
+
+#[test]
+fn set_voting_mode_with_live_proposals() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+		propose_remark(&supersig_account);
+
+		assert_noop!(
+			Supersig::set_voting_mode(RawOrigin::Signed(supersig_account).into(), VotingMode::Role),
+			Error::<Test>::ProposalsPending
+		);
+	})
+}
+
+#[test]
+fn token_weights_are_snapshotted() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+		propose_remark(&supersig_account);
+
+		assert_eq!(Supersig::vote_weight_snapshots((0, 0, ALICE())), 200_000_000);
+		assert_eq!(Supersig::vote_weight_snapshots((0, 0, BOB())), 500_000_000);
+		assert_eq!(Supersig::vote_weight_snapshots((0, 0, CHARLIE())), 300_000_000);
+		assert_eq!(Supersig::total_weight_snapshots(0, 0), 1_000_000_000);
+
+		// moving tokens after the proposal doesn't change the weights
+		assert_ok!(Assets::transfer(
+			RawOrigin::Signed(BOB()).into(),
+			ASSET.into(),
+			ALICE(),
+			500
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_eq!(Supersig::votes(0, 0), 200_000_000);
+		assert!(Supersig::calls(0, 0).is_some());
+	})
+}
+
+#[test]
+fn token_majority_executes_call() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+		propose_remark(&supersig_account);
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(CHARLIE()).into(),
+			supersig_account.clone(),
+			0
+		));
+		// 50% is not a majority
+		assert!(Supersig::calls(0, 0).is_some());
+
+		assert_ok!(Supersig::approve_call(
+			RawOrigin::Signed(BOB()).into(),
+			supersig_account.clone(),
+			0
+		));
+		assert!(Supersig::calls(0, 0).is_none());
+		assert_eq!(Supersig::total_weight_snapshots(0, 0), 0);
+		assert_eq!(Supersig::vote_weight_snapshots((0, 0, BOB())), 0);
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Supersig(crate::Event::CallExecutionAttempted(
+				supersig_account,
+				0,
+				Ok(().into())
+			))
+		);
+	})
+}
+
+#[test]
+fn propose_call_fails_without_asset_holder() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+		// none of the members holds this asset
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET + 1)
+		));
+
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_noop!(
+			Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account,
+				Box::new(call.into())
+			),
+			Error::<Test>::NoAssetHolder
+		);
+	})
+}
+
+#[test]
+fn propose_call_fails_with_too_many_members() {
+	ExtBuilder::default().balances(vec![]).build().execute_with(|| {
+		mint_asset();
+		let supersig_account = create_supersig(
+			vec![
+				(ALICE(), Role::Master),
+				(BOB(), Role::Standard),
+				(CHARLIE(), Role::Standard),
+			],
+			0,
+		);
+		assert_ok!(Supersig::set_voting_mode(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			VotingMode::Token(ASSET)
+		));
+		assert_ok!(Balances::transfer(
+			RawOrigin::Signed(ALICE()).into(),
+			supersig_account.clone(),
+			100_000
+		));
+		// MaxSnapshotSize is 4
+		assert_ok!(Supersig::add_members(
+			RawOrigin::Signed(supersig_account.clone()).into(),
+			vec![(PAUL(), Role::Standard), (DONALD(), Role::Standard)].try_into().unwrap()
+		));
+
+		let call = frame_system::Call::remark {
+			remark: "test".into(),
+		};
+		assert_noop!(
+			Supersig::propose_call(
+				RawOrigin::Signed(ALICE()).into(),
+				supersig_account,
+				Box::new(call.into())
+			),
+			Error::<Test>::SnapshotTooLarge
+		);
+	})
+}
@@ -0,0 +1,95 @@
+//! A relay chain and two parachains, connected with xcm-simulator. `ParaA` runs the test runtime
+//! of the pallet, `ParaB` is a remote chain.
+
+pub mod relay_chain;
+pub mod remote;
+
+use super::mock::{self, ExtBuilder};
+use codec::{Decode, Encode};
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::XcmExecutor;
+use xcm_simulator::{
+	decl_test_network, decl_test_parachain, decl_test_relay_chain, DmpMessageHandlerT,
+	ParaId, RelayBlockNumber, XcmpMessageFormat, XcmpMessageHandlerT,
+};
+
+/// Executes the XCM messages sent by the other parachains with `XcmConfig`. The messages of the
+/// relay chain are dropped.
+pub struct MessageHandler<XcmConfig>(PhantomData<XcmConfig>);
+
+impl<XcmConfig: xcm_executor::Config> XcmpMessageHandlerT for MessageHandler<XcmConfig> {
+	fn handle_xcmp_messages<'a, I: Iterator<Item = (ParaId, RelayBlockNumber, &'a [u8])>>(
+		iter: I,
+		max_weight: Weight,
+	) -> Weight {
+		for (sender, _sent_at, mut data) in iter {
+			XcmpMessageFormat::decode(&mut data).expect("the simulator sends versioned XCM; qed");
+			while !data.is_empty() {
+				let message = VersionedXcm::<XcmConfig::RuntimeCall>::decode(&mut data)
+					.expect("the simulator sends versioned XCM; qed");
+				let message = Xcm::<XcmConfig::RuntimeCall>::try_from(message)
+					.expect("latest version; qed");
+				let hash = message.using_encoded(sp_io::hashing::blake2_256);
+				let _ = XcmExecutor::<XcmConfig>::execute_xcm(
+					(Parent, Parachain(sender.into())),
+					message,
+					hash,
+					max_weight,
+				);
+			}
+		}
+		max_weight
+	}
+}
+
+impl<XcmConfig> DmpMessageHandlerT for MessageHandler<XcmConfig> {
+	fn handle_dmp_messages(
+		iter: impl Iterator<Item = (RelayBlockNumber, Vec<u8>)>,
+		_max_weight: Weight,
+	) -> Weight {
+		iter.for_each(drop);
+		Weight::zero()
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaA {
+		Runtime = mock::Test,
+		XcmpMessageHandler = MessageHandler<mock::XcmConfig>,
+		DmpMessageHandler = MessageHandler<mock::XcmConfig>,
+		new_ext = ExtBuilder::default().balances(vec![]).build(),
+	}
+}
+
+decl_test_parachain! {
+	pub struct ParaB {
+		Runtime = remote::Runtime,
+		XcmpMessageHandler = MessageHandler<remote::XcmConfig>,
+		DmpMessageHandler = MessageHandler<remote::XcmConfig>,
+		new_ext = remote::new_ext(),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay_chain::Runtime,
+		RuntimeCall = relay_chain::RuntimeCall,
+		RuntimeEvent = relay_chain::RuntimeEvent,
+		XcmConfig = relay_chain::XcmConfig,
+		MessageQueue = relay_chain::MessageQueue,
+		System = relay_chain::System,
+		new_ext = relay_chain::new_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct MockNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(1, ParaA),
+			(2, ParaB),
+		],
+	}
+}
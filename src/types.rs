@@ -3,8 +3,22 @@ use codec::{Decode, Encode};
 use frame_support::pallet_prelude::MaxEncodedLen;
 use scale_info::TypeInfo;
 
-pub type BalanceOf<T> =
-	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type BalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+pub type DepositAssetIdOf<T> = <<T as Config>::DepositAssets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+pub type DepositBalanceOf<T> = <<T as Config>::DepositAssets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+pub type AssetIdOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type AssetBalanceOf<T> =
+	<<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type CollectionIdOf<T> = <T as Config>::CollectionId;
+pub type ItemIdOf<T> = <T as Config>::ItemId;
+pub type VotingModeOf<T> = VotingMode<AssetIdOf<T>, CollectionIdOf<T>>;
 
 #[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -21,6 +35,91 @@ impl Default for Role {
 	}
 }
 
+/// How the members of a supersig, and their vote weight, are defined.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+pub enum VotingMode<AssetId, CollectionId> {
+	/// the weight depends on the `Role` of the member
+	Role,
+	/// the weight is the share of the given asset the member holds, among all the members,
+	/// snapshotted when a call is proposed
+	Token(AssetId),
+	/// the members are the holders of the items of the given collection, and each item is
+	/// worth one vote. The `Members` storage is ignored
+	Collection(CollectionId),
+}
+
+impl<AssetId, CollectionId> Default for VotingMode<AssetId, CollectionId> {
+	fn default() -> Self {
+		VotingMode::Role
+	}
+}
+
+/// The origin a proposed call is dispatched with, once approved.
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum CallOrigin {
+	/// a `Signed` origin, from the supersig account
+	Signed,
+	/// the pallet `Origin::Approved`, carrying the weight that approved the call
+	Approved,
+	/// a `Signed` origin, from the given vault of the supersig
+	Vault(VaultIndex),
+}
+
+impl Default for CallOrigin {
+	fn default() -> Self {
+		CallOrigin::Signed
+	}
+}
+
+/// Structural constraints a supersig must keep through every membership change.
+/// The default value doesn't constrain anything.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct Invariants {
+	/// minimum number of members with the `Master` role
+	pub min_masters: u32,
+	/// minimum sum of the role based vote weights of all the members
+	pub min_total_weight: u32,
+}
+
+/// An amount of native currency a member can spend from the supersig account, per period,
+/// without a vote.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct Allowance<Balance, BlockNumber> {
+	/// amount granted for each period
+	pub amount: Balance,
+	/// length of a period, in blocks
+	pub period: BlockNumber,
+	/// amount left to spend in the current period
+	pub remaining: Balance,
+	/// block at which the current period started
+	pub period_start: BlockNumber,
+}
+
+/// A cap on the native currency leaving the supersig account through executed calls.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutflowLimit<Balance, BlockNumber> {
+	/// maximum amount that can leave the account during a window
+	pub max_amount: Balance,
+	/// length of a window, in blocks
+	pub window: BlockNumber,
+}
+
+/// A fallback beneficiary for a supersig whose members are all inactive.
+#[derive(Clone, Encode, Decode, TypeInfo, PartialEq, Eq, MaxEncodedLen, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inheritance<AccountId, BlockNumber> {
+	/// account that can claim the supersig funds
+	pub beneficiary: AccountId,
+	/// number of blocks without proposal or vote after which the beneficiary can claim
+	pub inactivity_period: BlockNumber,
+	/// number of blocks during which the members can cancel a claim
+	pub challenge_period: BlockNumber,
+}
+
 #[derive(Clone, Encode, Decode, TypeInfo, Debug)]
 #[scale_info(skip_type_params(T))]
 pub struct PreimageCall<AccountId, Balance> {
@@ -30,4 +129,6 @@ pub struct PreimageCall<AccountId, Balance> {
 }
 
 pub type SupersigId = u128;
+pub type Salt = [u8; 32];
+pub type VaultIndex = u16;
 pub type CallId = u128;
@@ -39,6 +39,27 @@ pub trait WeightInfo {
     fn remove_members(z: u32, ) -> Weight;
     fn delete_supersig() -> Weight;
     fn leave_supersig() -> Weight;
+    fn rotate_member(z: u32, ) -> Weight;
+    fn set_invariants() -> Weight;
+    fn set_voting_mode() -> Weight;
+    fn create_supersig_from_collection() -> Weight;
+    fn approve_call_as_child() -> Weight;
+    fn set_allowance() -> Weight;
+    fn spend_allowance() -> Weight;
+    fn set_outflow_limit() -> Weight;
+    fn create_supersig_with_salt(z: u32, ) -> Weight;
+    fn set_guardians(z: u32, ) -> Weight;
+    fn freeze() -> Weight;
+    fn unfreeze() -> Weight;
+    fn set_inheritance() -> Weight;
+    fn claim_inheritance() -> Weight;
+    fn cancel_inheritance_claim() -> Weight;
+    fn execute_inheritance() -> Weight;
+    fn propose_call_with_origin(z: u32, ) -> Weight;
+    fn add_vault() -> Weight;
+    fn send_xcm() -> Weight;
+    fn set_remote_voter() -> Weight;
+    fn approve_call_remote() -> Weight;
 }
 
 /// Weight functions for `pallet_supersig`.
@@ -135,5 +156,154 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4u64))
 			.saturating_add(T::DbWeight::get().writes(4u64))
 	}
-}
+	// NOT BENCHMARKED: the weights below were not generated, their benchmarks in
+	// `benchmarking.rs` are still to be run. Each one is the weight of a benchmarked extrinsic
+	// doing similar work, plus the database accesses counted from the code on top of it. Looking
+	// up a supersig from its account counts as 4 reads.
 
+	// leave_supersig, plus the lookup, Frozen, both Members, PendingChildVotes, Allowances and
+	// RemoteVoterLocations reads, and the Members, AccountSupersigs, Allowances and
+	// RemoteVoterLocations writes.
+	// Each pending call reads its key and moves a MembersVotes and a VoteWeightSnapshots entry
+	fn rotate_member(z: u32, ) -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(10u64))
+			.saturating_add(T::DbWeight::get().writes(7u64))
+			.saturating_add(T::DbWeight::get().reads((3u64).saturating_mul(z as u64)))
+			.saturating_add(T::DbWeight::get().writes((4u64).saturating_mul(z as u64)))
+	}
+	// leave_supersig, plus the lookup, SupersigInvariants, TotalMasters and TotalMembers reads,
+	// and the SupersigInvariants write
+	fn set_invariants() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(7u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup, ActiveProposals and TotalMembers reads, and the
+	// VotingModes write
+	fn set_voting_mode() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(6u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// create_supersig without members, plus the NonceSupersig, provider, transfers, escrow
+	// balance and TotalDeposit reads, and the NonceSupersig, VotingModes, provider, transfers and
+	// TotalDeposit writes
+	fn create_supersig_from_collection() -> Weight {
+		Self::create_supersig(0)
+			.saturating_add(T::DbWeight::get().reads(8u64))
+			.saturating_add(T::DbWeight::get().writes(8u64))
+	}
+	// approve_call, which it runs once the child threshold is reached, plus the two lookups,
+	// both Frozen, Calls, parent membership, child VotingModes, ChildMembersVotes, Members,
+	// TotalMembers, PendingChildVotes and ChildVotes reads, and the ChildMembersVotes,
+	// PendingChildVotes and ChildVotes writes. The removal of the ChildMembersVotes entry, and
+	// the PendingChildVotes update it makes, are counted too
+	fn approve_call_as_child() -> Weight {
+		Self::approve_call()
+			.saturating_add(T::DbWeight::get().reads(21u64))
+			.saturating_add(T::DbWeight::get().writes(6u64))
+	}
+	// leave_supersig, plus the lookup and membership reads, and the Allowances write
+	fn set_allowance() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(6u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup, Frozen, membership, Allowances, OutflowLimits, Outflows and
+	// transfer reads, and the Outflows, transfer and Allowances writes
+	fn spend_allowance() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(12u64))
+			.saturating_add(T::DbWeight::get().writes(4u64))
+	}
+	// leave_supersig, plus the lookup reads, and the OutflowLimits and Outflows writes
+	fn set_outflow_limit() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(4u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// create_supersig, plus the SaltedSupersigs read, and the SaltedSupersigs and SaltedAccounts
+	// writes
+	fn create_supersig_with_salt(z: u32, ) -> Weight {
+		Self::create_supersig(z)
+			.saturating_add(T::DbWeight::get().reads(1u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// leave_supersig, plus the lookup reads, and the Guardians write
+	fn set_guardians(_z: u32, ) -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(4u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup, Members, Guardians and Frozen reads, and the Frozen write
+	fn freeze() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(7u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup and Frozen reads, and the Frozen write
+	fn unfreeze() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(5u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup reads, and the Inheritances and InheritanceClaims writes
+	fn set_inheritance() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(4u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// leave_supersig, plus the lookup, Inheritances, InheritanceClaims and LastActivity reads, and
+	// the InheritanceClaims write
+	fn claim_inheritance() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(7u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup, membership and InheritanceClaims reads, and the
+	// InheritanceClaims and LastActivity writes
+	fn cancel_inheritance_claim() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(7u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// delete_supersig, plus the Inheritances and InheritanceClaims reads and removals
+	fn execute_inheritance() -> Weight {
+		Self::delete_supersig()
+			.saturating_add(T::DbWeight::get().reads(2u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// propose_call, plus the TotalMembers and TotalMasters reads checking the threshold, and the
+	// CallOrigins and CallThresholds writes
+	fn propose_call_with_origin(z: u32, ) -> Weight {
+		Self::propose_call(z)
+			.saturating_add(T::DbWeight::get().reads(2u64))
+			.saturating_add(T::DbWeight::get().writes(2u64))
+	}
+	// leave_supersig, plus the lookup and VaultCount reads, and the VaultCount write
+	fn add_vault() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(5u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup reads, and the System Account read and write of the
+	// delivery price charged through `XcmExecutor`. The routing by `XcmRouter` is not counted
+	fn send_xcm() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(5u64))
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// leave_supersig, plus the lookup, membership and RemoteVoters reads, and the RemoteVoters
+	// and RemoteVoterLocations writes
+	fn set_remote_voter() -> Weight {
+		Self::leave_supersig()
+			.saturating_add(T::DbWeight::get().reads(7u64))
+			.saturating_add(T::DbWeight::get().writes(4u64))
+	}
+	// approve_call, plus the lookup and RemoteVoters reads
+	fn approve_call_remote() -> Weight {
+		Self::approve_call()
+			.saturating_add(T::DbWeight::get().reads(5u64))
+	}
+}